@@ -1,5 +1,4 @@
 use std::{
-    borrow::Cow,
     fs::read_to_string,
     io::{self, stdout},
 };
@@ -15,7 +14,7 @@ use ratatui::{
     widgets::{Block, BorderType, Borders, Clear, FrameExt, Paragraph},
 };
 
-use ratatui_explorer::{File, FileExplorerBuilder, Theme};
+use ratatui_explorer::{File, FileExplorerBuilder, Outcome, Theme};
 
 fn main() -> io::Result<()> {
     enable_raw_mode()?;
@@ -30,15 +29,10 @@ fn main() -> io::Result<()> {
         .show_hidden(true)
         .build()?;
 
-    loop {
-        // Get the content of the current selected file (if it's indeed a file).
-        let file_content = get_file_content(file_explorer.current());
-
-        let file_content = match file_content {
-            Ok(file_content) => file_content,
-            _ => "Couldn't load file.".into(),
-        };
+    // Get the content of the current selected file (if it's indeed a file).
+    let mut file_content = get_file_content(file_explorer.current());
 
+    loop {
         // Render the file explorer widget and the file content.
         terminal.draw(|f| {
             let chunks = layout.split(f.area());
@@ -46,7 +40,7 @@ fn main() -> io::Result<()> {
             f.render_widget_ref(file_explorer.widget(), chunks[0]);
             f.render_widget(Clear, chunks[1]);
             f.render_widget(
-                Paragraph::new(file_content).block(
+                Paragraph::new(file_content.as_str()).block(
                     Block::default()
                         .borders(Borders::ALL)
                         .border_type(BorderType::Double),
@@ -62,8 +56,13 @@ fn main() -> io::Result<()> {
         {
             break;
         }
-        // Handle the event in the file explorer.
-        file_explorer.handle(&event)?;
+        // Handle the event in the file explorer and refresh the preview only if the selection changed.
+        match file_explorer.handle(&event)? {
+            Outcome::SelectionChanged | Outcome::DirectoryChanged { .. } => {
+                file_content = get_file_content(file_explorer.current());
+            }
+            _ => {}
+        }
     }
 
     disable_raw_mode()?;
@@ -71,14 +70,14 @@ fn main() -> io::Result<()> {
     Ok(())
 }
 
-fn get_file_content(file: &File) -> io::Result<Cow<'_, str>> {
+fn get_file_content(file: &File) -> String {
     // If the path is a file, read its content.
     if file.is_file() {
-        read_to_string(&file.path).map(Into::into)
+        read_to_string(&file.path).unwrap_or_else(|_| "Couldn't load file.".into())
     } else if file.is_dir {
-        Ok("".into())
+        String::new()
     } else {
-        Ok("<not a regular file>".into())
+        "<not a regular file>".into()
     }
 }
 
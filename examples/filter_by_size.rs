@@ -1,7 +1,4 @@
-use std::{
-    io::{self, stdout},
-    sync::{Arc, Mutex},
-};
+use std::io::{self, stdout};
 
 use crossterm::{
     ExecutableCommand,
@@ -14,7 +11,7 @@ use ratatui::{
     widgets::{Block, BorderType},
 };
 
-use ratatui_explorer::{File, FileExplorerBuilder, Theme};
+use ratatui_explorer::{File, FileExplorerBuilder, Filter, RowContext, Theme};
 
 const MIN_SIZE: u64 = 10 << 8; // 10kiB
 
@@ -24,14 +21,12 @@ fn main() -> io::Result<()> {
 
     let mut terminal = Terminal::new(CrosstermBackend::new(stdout()))?;
 
-    let show_all_file = Arc::new(Mutex::new(true));
-    let status = Arc::clone(&show_all_file);
-
     // Create a new file explorer with the default theme and titles showing the current status of the filter.
     let mut file_explorer = FileExplorerBuilder::default()
-        .theme(get_theme(status))
-        .filter_map(|file| filter_hight_volume(file, 0)) // Add our filter to the file explorer
+        .theme(get_theme())
+        .filter("big", Filter::size(MIN_SIZE..)) // Add our filter to the file explorer
         .build()?;
+    file_explorer.set_filter_enabled("big", false)?;
 
     loop {
         // Render the file explorer widget.
@@ -47,19 +42,7 @@ fn main() -> io::Result<()> {
         if let Event::Key(key) = event {
             match key.code {
                 KeyCode::Char('q') => break,
-                KeyCode::Char('t') => {
-                    let mut show_all_file = show_all_file.lock().unwrap();
-                    *show_all_file = !*show_all_file;
-                    if *show_all_file {
-                        file_explorer
-                            .set_filter_map(|file| filter_hight_volume(file, 0))
-                            .unwrap();
-                    } else {
-                        file_explorer
-                            .set_filter_map(|file| filter_hight_volume(file, MIN_SIZE))
-                            .unwrap();
-                    }
-                }
+                KeyCode::Char('t') => file_explorer.toggle_filter("big")?,
                 _ => {}
             }
         }
@@ -73,24 +56,26 @@ fn main() -> io::Result<()> {
     Ok(())
 }
 
-fn filter_hight_volume(mut file: File, min_size: u64) -> Option<File> {
+// Show the size of the files before their names.
+fn show_size(file: &File, _: RowContext) -> Text<'_> {
+    let green_style = Style::default().fg(Color::Green);
+
     if file.is_dir {
-        return Some(file);
+        return Span::styled(&file.name, green_style.add_modifier(Modifier::BOLD)).into();
     }
 
-    let metadata = file.path.metadata().ok()?;
-    let size = metadata.len();
-
-    if size < min_size {
-        None
-    } else {
-        let size = format!("{size}B");
-        file.name = format!("{:>6}  {}", size, file.name);
-        Some(file)
-    }
+    let size = match file.path.metadata() {
+        Ok(metadata) => format!("{}B", metadata.len()),
+        Err(_) => "?".to_owned(),
+    };
+    Line::from(vec![
+        Span::styled(format!("{size:>6}  "), Style::default().fg(Color::DarkGray)),
+        Span::styled(&file.name, green_style),
+    ])
+    .into()
 }
 
-fn get_theme(status: Arc<Mutex<bool>>) -> Theme {
+fn get_theme() -> Theme {
     let green_style: Style = Style::default().fg(Color::Green);
 
     Theme::default()
@@ -103,17 +88,18 @@ fn get_theme(status: Arc<Mutex<bool>>) -> Theme {
         .with_highlight_dir_style(green_style.add_modifier(Modifier::BOLD).bg(Color::DarkGray))
         .with_item_style(green_style)
         .with_highlight_item_style(green_style.bg(Color::DarkGray))
+        .with_row_renderer(show_size)
         .add_default_title()
         .with_title_bottom(|_| " q Quit | t Toggle filter ".into())
-        .with_title_top(move |_| {
-            if *status.lock().unwrap() {
-                Line::from(format!(" filter: OFF ({}kiB) ", MIN_SIZE >> 8))
-                    .style(Style::default().fg(Color::Red))
-                    .right_aligned()
-            } else {
+        .with_title_top(move |file_explorer| {
+            if file_explorer.is_filter_enabled("big") {
                 Line::from(format!(" filter: ON  ({}kiB) ", MIN_SIZE >> 8))
                     .style(green_style)
                     .right_aligned()
+            } else {
+                Line::from(format!(" filter: OFF ({}kiB) ", MIN_SIZE >> 8))
+                    .style(Style::default().fg(Color::Red))
+                    .right_aligned()
             }
         })
 }
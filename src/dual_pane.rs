@@ -0,0 +1,524 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use ratatui::widgets::WidgetRef;
+
+use crate::{FileExplorer, Input, Outcome, Result, widget::DualPaneRenderer};
+
+/// One of the two panes of a [`DualPane`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Pane {
+    /// The pane on the left.
+    #[default]
+    Left,
+    /// The pane on the right.
+    Right,
+}
+
+impl Pane {
+    /// Returns the other pane.
+    #[must_use]
+    pub const fn other(self) -> Self {
+        match self {
+            Pane::Left => Pane::Right,
+            Pane::Right => Pane::Left,
+        }
+    }
+}
+
+/// Two [`FileExplorer`]s side by side, like in Midnight Commander: one of them has the focus, and the files
+/// can be copied or moved from it to the other one.
+///
+/// The panes can be opened from the same [`Bookmarks`](crate::Bookmarks) and [`Frecency`](crate::Frecency)
+/// files: the bookmarks and the visits of both panes are saved to them without overwriting the others.
+///
+/// # Examples
+///
+/// ```no_run
+/// use ratatui::widgets::FrameExt;
+/// use ratatui_explorer::{DualPane, FileExplorer, Input};
+///
+/// let mut dual_pane = DualPane::new(
+///     FileExplorer::new().unwrap(),
+///     FileExplorer::new().unwrap(),
+/// );
+///
+/// # fn draw(f: &mut ratatui::Frame, dual_pane: &DualPane) {
+/// f.render_widget_ref(dual_pane.widget(), f.area());
+/// # }
+/// # fn get_event() -> Input { Input::None }
+///
+/// // `SwitchPane` gives the focus to the other pane.
+/// dual_pane.handle(get_event()).unwrap();
+///
+/// /* the user presses `F5` */
+/// dual_pane.copy_to_other().unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DualPane {
+    left: FileExplorer,
+    right: FileExplorer,
+    focused: Pane,
+    sync: bool,
+}
+
+impl DualPane {
+    /// Creates a dual pane from the file explorers of its left and right panes. The left pane has the focus.
+    #[must_use]
+    pub const fn new(left: FileExplorer, right: FileExplorer) -> Self {
+        Self {
+            left,
+            right,
+            focused: Pane::Left,
+            sync: false,
+        }
+    }
+
+    /// Returns the file explorer of `pane`.
+    #[inline]
+    #[must_use]
+    pub const fn pane(&self, pane: Pane) -> &FileExplorer {
+        match pane {
+            Pane::Left => &self.left,
+            Pane::Right => &self.right,
+        }
+    }
+
+    /// Returns the file explorer of `pane`, mutably.
+    #[inline]
+    pub const fn pane_mut(&mut self, pane: Pane) -> &mut FileExplorer {
+        match pane {
+            Pane::Left => &mut self.left,
+            Pane::Right => &mut self.right,
+        }
+    }
+
+    /// Returns the pane having the focus.
+    #[inline]
+    #[must_use]
+    pub const fn focused(&self) -> Pane {
+        self.focused
+    }
+
+    /// Gives the focus to `pane`.
+    #[inline]
+    pub const fn set_focused(&mut self, pane: Pane) {
+        self.focused = pane;
+    }
+
+    /// Gives the focus to the other pane.
+    #[inline]
+    pub const fn switch_pane(&mut self) {
+        self.focused = self.focused.other();
+    }
+
+    /// Returns the file explorer of the pane having the focus.
+    #[inline]
+    #[must_use]
+    pub const fn active(&self) -> &FileExplorer {
+        self.pane(self.focused)
+    }
+
+    /// Returns the file explorer of the pane having the focus, mutably.
+    #[inline]
+    pub const fn active_mut(&mut self) -> &mut FileExplorer {
+        self.pane_mut(self.focused)
+    }
+
+    /// Returns the file explorer of the pane not having the focus.
+    #[inline]
+    #[must_use]
+    pub const fn inactive(&self) -> &FileExplorer {
+        self.pane(self.focused.other())
+    }
+
+    /// Sets whether the panes are kept in the same directory: when the working directory of a pane changes,
+    /// the other pane follows it. Enabling it moves the other pane to the directory of the focused one.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the other pane can not enter the directory of the focused one.
+    pub fn set_sync(&mut self, sync: bool) -> Result<()> {
+        self.sync = sync;
+        if sync {
+            self.follow_active()?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns whether the panes are kept in the same directory (see [`set_sync`](DualPane::set_sync)).
+    #[inline]
+    #[must_use]
+    pub const fn sync(&self) -> bool {
+        self.sync
+    }
+
+    /// Returns a widget rendering the two panes side by side, each one below a header showing its working
+    /// directory. The header of the focused pane is highlighted.
+    #[inline]
+    #[must_use]
+    pub const fn widget(&self) -> impl WidgetRef + '_ {
+        DualPaneRenderer(self)
+    }
+
+    /// Handles the input from the user.
+    ///
+    /// [`Input::SwitchPane`] gives the focus to the other pane, the other inputs are handled by the file
+    /// explorer of the focused pane (see [`FileExplorer::handle`]). If the panes are
+    /// [kept in sync](DualPane::set_sync), the other pane follows the focused one when its directory changes.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the file explorer of the focused pane returns one, or if the other pane can not
+    /// follow it.
+    pub fn handle<I: Into<Input>>(&mut self, input: I) -> Result<Outcome> {
+        let input = input.into();
+        if input == Input::SwitchPane {
+            self.switch_pane();
+            return Ok(Outcome::Nothing);
+        }
+
+        let outcome = self.active_mut().handle(input)?;
+        if self.sync && matches!(outcome, Outcome::DirectoryChanged { .. }) {
+            self.follow_active()?;
+        }
+
+        Ok(outcome)
+    }
+
+    /// Copies the marked entries of the focused pane, or its selected entry if none is marked, to the working
+    /// directory of the other pane. Returns the paths of the copies.
+    ///
+    /// The directories are copied recursively. The marks are cleared and both panes are listed again.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if an entry can not be copied, or if a file with the same name already exists in the
+    /// destination. The entries copied before the failure are kept.
+    #[inline]
+    pub fn copy_to_other(&mut self) -> Result<Vec<PathBuf>> {
+        let destination = self.inactive().cwd().clone();
+        self.copy_to(&destination)
+    }
+
+    /// Same as [`copy_to_other`](DualPane::copy_to_other), but copies the entries to `destination`.
+    ///
+    /// # Errors
+    ///
+    /// See [`copy_to_other`](DualPane::copy_to_other).
+    pub fn copy_to(&mut self, destination: &Path) -> Result<Vec<PathBuf>> {
+        self.transfer(destination, |source, target| {
+            copy_recursively(source, target)
+        })
+    }
+
+    /// Moves the marked entries of the focused pane, or its selected entry if none is marked, to the working
+    /// directory of the other pane. Returns the new paths of the entries.
+    ///
+    /// The marks are cleared and both panes are listed again.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if an entry can not be moved, or if a file with the same name already exists in the
+    /// destination. The entries moved before the failure are kept.
+    #[inline]
+    pub fn move_to_other(&mut self) -> Result<Vec<PathBuf>> {
+        let destination = self.inactive().cwd().clone();
+        self.move_to(&destination)
+    }
+
+    /// Same as [`move_to_other`](DualPane::move_to_other), but moves the entries to `destination`.
+    ///
+    /// # Errors
+    ///
+    /// See [`move_to_other`](DualPane::move_to_other).
+    pub fn move_to(&mut self, destination: &Path) -> Result<Vec<PathBuf>> {
+        self.transfer(destination, |source, target| {
+            match fs::rename(source, target) {
+                // A rename can not move a file to another filesystem.
+                Err(err) if err.kind() == io::ErrorKind::CrossesDevices => {
+                    copy_recursively(source, target)?;
+                    remove_recursively(source)
+                }
+                result => result,
+            }
+        })
+    }
+
+    /// Applies `operation` to the entries picked in the focused pane and their paths in `destination`.
+    fn transfer(
+        &mut self,
+        destination: &Path,
+        operation: impl Fn(&Path, &Path) -> io::Result<()>,
+    ) -> Result<Vec<PathBuf>> {
+        let active = self.active();
+        let sources: Vec<PathBuf> = if active.marked().next().is_some() {
+            active.marked().map(Path::to_path_buf).collect()
+        } else {
+            active
+                .try_current()
+                .filter(|file| !file.is_parent_entry())
+                .map(|file| vec![file.path.clone()])
+                .unwrap_or_default()
+        };
+
+        let mut targets = Vec::with_capacity(sources.len());
+        let result = sources.iter().try_for_each(|source| {
+            let Some(file_name) = source.file_name() else {
+                return Ok(());
+            };
+            let target = destination.join(file_name);
+            // The destination may reach the source through a symbolic link. The source itself is not resolved:
+            // a link is copied as a link, not followed.
+            let canonical_source = match source.parent() {
+                Some(parent) => parent.canonicalize()?.join(file_name),
+                None => source.clone(),
+            };
+            if destination
+                .canonicalize()?
+                .join(file_name)
+                .starts_with(canonical_source)
+            {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("can not put {} inside itself", source.display()),
+                ));
+            }
+            if target.symlink_metadata().is_ok() {
+                return Err(io::Error::new(
+                    io::ErrorKind::AlreadyExists,
+                    format!("{} already exists", target.display()),
+                ));
+            }
+
+            operation(source, &target).map_err(|err| {
+                io::Error::new(err.kind(), format!("{}: {err}", source.display()))
+            })?;
+            targets.push(target);
+            Ok(())
+        });
+
+        self.active_mut().clear_marks();
+        // The transfer is reported even if a pane can not be listed again, which its error hook is told about.
+        let _ = self.left.refresh();
+        let _ = self.right.refresh();
+        result?;
+
+        Ok(targets)
+    }
+
+    /// Moves the other pane to the working directory of the focused one.
+    fn follow_active(&mut self) -> Result<()> {
+        let cwd = self.active().cwd().clone();
+        let other = self.pane_mut(self.focused.other());
+        if *other.cwd() != cwd {
+            other.set_cwd(cwd)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Copies the file or directory `source` to `target`, recreating the symbolic links.
+fn copy_recursively(source: &Path, target: &Path) -> io::Result<()> {
+    let file_type = source.symlink_metadata()?.file_type();
+
+    if file_type.is_symlink() {
+        return copy_symlink(source, target, file_type);
+    }
+    if file_type.is_dir() {
+        fs::create_dir(target)?;
+        for entry in fs::read_dir(source)? {
+            let entry = entry?;
+            copy_recursively(&entry.path(), &target.join(entry.file_name()))?;
+        }
+        return Ok(());
+    }
+
+    fs::copy(source, target).map(|_| ())
+}
+
+/// Creates a symbolic link at `target` pointing where the link `source` points.
+#[cfg(unix)]
+fn copy_symlink(source: &Path, target: &Path, _file_type: fs::FileType) -> io::Result<()> {
+    std::os::unix::fs::symlink(fs::read_link(source)?, target)
+}
+
+/// Creates a symbolic link at `target` pointing where the link `source` points.
+#[cfg(windows)]
+fn copy_symlink(source: &Path, target: &Path, file_type: fs::FileType) -> io::Result<()> {
+    use std::os::windows::fs::{FileTypeExt, symlink_dir, symlink_file};
+
+    // Windows tells links to directories from links to files.
+    if file_type.is_symlink_dir() {
+        symlink_dir(fs::read_link(source)?, target)
+    } else {
+        symlink_file(fs::read_link(source)?, target)
+    }
+}
+
+/// Symbolic links can not be created on this platform, and copying their target could copy a whole tree.
+#[cfg(not(any(unix, windows)))]
+fn copy_symlink(source: &Path, _target: &Path, _file_type: fs::FileType) -> io::Result<()> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        format!("can not copy the symbolic link {}", source.display()),
+    ))
+}
+
+/// Removes the file or directory `path`, without following the symbolic links.
+fn remove_recursively(path: &Path) -> io::Result<()> {
+    if path.symlink_metadata()?.is_dir() {
+        fs::remove_dir_all(path)
+    } else {
+        fs::remove_file(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use tempfile::TempDir;
+
+    use crate::FileExplorerBuilder;
+
+    /// Build this temporary file system:
+    /// ```plaintext
+    /// <unknow>
+    /// └ root
+    ///   ├── left
+    ///   │   ├── photos
+    ///   │   │   └── cat.png
+    ///   │   ├── notes.txt
+    ///   │   └── todo.txt
+    ///   └── right
+    ///       └── todo.txt
+    /// ```
+    fn build_tmp_file_system() -> Result<TempDir> {
+        let root = TempDir::new()?;
+
+        fs::create_dir_all(root.path().join("left/photos"))?;
+        fs::create_dir_all(root.path().join("right"))?;
+        fs::write(root.path().join("left/photos/cat.png"), "meow")?;
+        fs::write(root.path().join("left/notes.txt"), "notes")?;
+        fs::write(root.path().join("left/todo.txt"), "left")?;
+        fs::write(root.path().join("right/todo.txt"), "right")?;
+
+        Ok(root)
+    }
+
+    fn names(file_explorer: &FileExplorer) -> Vec<&str> {
+        file_explorer
+            .files()
+            .iter()
+            .map(|file| file.name.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_copy_and_move_to_other_pane() -> Result<()> {
+        let root = build_tmp_file_system()?;
+
+        let mut dual_pane = DualPane::new(
+            FileExplorerBuilder::build_with_working_dir(root.path().join("left"))?,
+            FileExplorerBuilder::build_with_working_dir(root.path().join("right"))?,
+        );
+
+        // Copy `photos/` and `notes.txt`.
+        dual_pane.handle(Input::Down)?;
+        dual_pane.handle(Input::ToggleMark)?;
+        dual_pane.handle(Input::ToggleMark)?;
+        let copies = dual_pane.copy_to_other()?;
+        assert_eq!(
+            copies,
+            [
+                root.path().join("right/notes.txt"),
+                root.path().join("right/photos")
+            ]
+        );
+        assert_eq!(
+            fs::read_to_string(root.path().join("right/photos/cat.png"))?,
+            "meow"
+        );
+        assert_eq!(
+            names(dual_pane.pane(Pane::Right)),
+            ["../", "photos/", "notes.txt", "todo.txt"]
+        );
+        assert_eq!(dual_pane.active().marked().count(), 0);
+
+        // `todo.txt` already exists in the other pane.
+        dual_pane.handle(Input::End)?;
+        assert!(dual_pane.move_to_other().is_err());
+        assert_eq!(
+            fs::read_to_string(root.path().join("right/todo.txt"))?,
+            "right"
+        );
+
+        dual_pane.handle(Input::SwitchPane)?;
+        assert_eq!(dual_pane.focused(), Pane::Right);
+        dual_pane.handle(Input::End)?;
+        dual_pane
+            .active_mut()
+            .set_marked(root.path().join("right/notes.txt"), true);
+        let moved = dual_pane.move_to(&root.path().join("left/photos"))?;
+        assert_eq!(moved, [root.path().join("left/photos/notes.txt")]);
+        assert_eq!(
+            names(dual_pane.pane(Pane::Right)),
+            ["../", "photos/", "todo.txt"]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_synced_panes_follow_each_other() -> Result<()> {
+        let root = build_tmp_file_system()?;
+
+        let mut dual_pane = DualPane::new(
+            FileExplorerBuilder::build_with_working_dir(root.path().join("left"))?,
+            FileExplorerBuilder::build_with_working_dir(root.path().join("right"))?,
+        );
+        dual_pane.set_sync(true)?;
+        assert_eq!(dual_pane.inactive().cwd(), &root.path().join("left"));
+
+        dual_pane.handle(Input::Down)?;
+        dual_pane.handle(Input::Right)?;
+        assert_eq!(
+            dual_pane.pane(Pane::Right).cwd(),
+            &root.path().join("left/photos")
+        );
+
+        dual_pane.set_sync(false)?;
+        dual_pane.handle(Input::Left)?;
+        assert_eq!(
+            dual_pane.pane(Pane::Right).cwd(),
+            &root.path().join("left/photos")
+        );
+
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_copy_into_itself_through_a_link_is_refused() -> Result<()> {
+        let root = build_tmp_file_system()?;
+        let link_path = root.path().join("right/link");
+        std::os::unix::fs::symlink(root.path().join("left/photos"), &link_path)?;
+
+        let mut dual_pane = DualPane::new(
+            FileExplorerBuilder::build_with_working_dir(root.path().join("left"))?,
+            FileExplorerBuilder::build_with_working_dir(&link_path)?,
+        );
+
+        // Select `photos/`, which the right pane lists through `link`.
+        dual_pane.handle(Input::Down)?;
+        assert!(dual_pane.copy_to_other().is_err());
+        assert!(!root.path().join("left/photos/photos").exists());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,91 @@
+use std::{
+    fmt, io,
+    path::{Path, PathBuf},
+};
+
+/// A specialized [`Result`](std::result::Result) type for the file explorer.
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// The errors returned by the [`FileExplorer`](crate::FileExplorer).
+///
+/// It can be converted into an [`io::Error`] with the `?` operator.
+#[derive(Debug)]
+pub enum Error {
+    /// The directory does not exist (anymore).
+    NotFound(PathBuf),
+    /// The directory can not be entered, because the system refused it, it is outside of the
+    /// [root](crate::FileExplorerBuilder::root), the [guard](crate::FileExplorerBuilder::guard) does not allow it
+    /// or it is reached through a symbolic link loop.
+    PermissionDenied {
+        /// The directory that can not be entered.
+        path: PathBuf,
+        /// Why the directory can not be entered.
+        reason: String,
+    },
+    /// A filter can not be built (eg. an invalid pattern).
+    Filter(String),
+    /// Any other I/O error.
+    Io(io::Error),
+}
+
+impl Error {
+    /// Returns the [`io::ErrorKind`] corresponding to this error.
+    #[must_use]
+    pub fn kind(&self) -> io::ErrorKind {
+        match self {
+            Error::NotFound(_) => io::ErrorKind::NotFound,
+            Error::PermissionDenied { .. } => io::ErrorKind::PermissionDenied,
+            Error::Filter(_) => io::ErrorKind::InvalidInput,
+            Error::Io(err) => err.kind(),
+        }
+    }
+
+    /// Converts an error that happened while listing `path`.
+    pub(crate) fn with_path(err: io::Error, path: &Path) -> Self {
+        match err.kind() {
+            io::ErrorKind::NotFound => Error::NotFound(path.to_path_buf()),
+            io::ErrorKind::PermissionDenied => Error::PermissionDenied {
+                path: path.to_path_buf(),
+                reason: "permission denied".to_owned(),
+            },
+            _ => Error::Io(err),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::NotFound(path) => write!(f, "{} does not exist", path.display()),
+            Error::PermissionDenied { path, reason } => {
+                write!(f, "can not enter {}: {reason}", path.display())
+            }
+            Error::Filter(message) => write!(f, "invalid filter: {message}"),
+            Error::Io(err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+impl From<Error> for io::Error {
+    fn from(err: Error) -> Self {
+        match err {
+            Error::Io(err) => err,
+            err => io::Error::new(err.kind(), err),
+        }
+    }
+}
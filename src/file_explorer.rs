@@ -1,20 +1,52 @@
 use std::{
-    io::Result,
-    path::{Path, PathBuf},
-    sync::Arc,
+    collections::BTreeSet,
+    ffi::OsString,
+    path::{Component, Path, PathBuf},
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
 };
 
 use ratatui::widgets::WidgetRef;
 
-use crate::{Theme, input::Input, widget::Renderer};
+use crate::{Error, Result, Theme, input::Input, widget::Renderer};
 
+mod bookmarks;
 mod builder;
+mod dialog;
+mod disk_usage;
 mod file;
-
+mod filter;
+mod find;
+mod flatten;
+mod frecency;
+mod git;
+#[cfg(feature = "gitignore")]
+mod gitignore;
+mod hooks;
+mod marks;
+mod outcome;
+mod prompt;
+
+use dialog::Dialog;
+use disk_usage::DiskUsage;
+use filter::NamedFilter;
+use find::Find;
+use hooks::Hooks;
+
+pub use bookmarks::Bookmarks;
 pub use builder::FileExplorerBuilder;
-pub use file::File;
+pub use dialog::DialogMode;
+pub use file::{File, FileKind, NameDecoration};
+pub use filter::Filter;
+pub use frecency::Frecency;
+pub use git::GitStatus;
+pub use outcome::Outcome;
+pub use prompt::{Prompt, PromptKind};
 
-type Filter = dyn Fn(File) -> Option<File> + Send + Sync + 'static;
+type FilterMap = dyn Fn(File) -> Option<File> + Send + Sync + 'static;
+type Guard = dyn Fn(&Path) -> std::result::Result<(), String> + Send + Sync + 'static;
 
 /// A file explorer that allows browsing and selecting files and directories.
 ///
@@ -63,10 +95,40 @@ pub struct FileExplorer {
     cwd: PathBuf,
     files: Vec<File>,
     show_hidden: bool,
+    #[cfg(feature = "gitignore")]
+    respect_ignore: bool,
+    name_decoration: NameDecoration,
+    follow_dir_symlinks: bool,
+    flatten: bool,
+    flatten_depth: usize,
     selected: usize,
     theme: Theme,
     #[educe(Debug(ignore), PartialEq(ignore), Hash(ignore))]
-    filter: Option<Arc<Filter>>,
+    filter: Option<Arc<FilterMap>>,
+    #[educe(PartialEq(ignore), Hash(ignore))]
+    filters: Vec<NamedFilter>,
+    #[educe(Debug(ignore), PartialEq(ignore), Hash(ignore))]
+    guard: Option<Arc<Guard>>,
+    message: Option<String>,
+    marked: BTreeSet<PathBuf>,
+    hovered: Option<usize>,
+    search: Option<String>,
+    #[educe(PartialEq(ignore), Hash(ignore))]
+    find: Option<Find>,
+    #[educe(PartialEq(ignore), Hash(ignore))]
+    disk_usage: Option<DiskUsage>,
+    #[cfg(feature = "git")]
+    #[educe(PartialEq(ignore), Hash(ignore))]
+    git: git::Git,
+    dialog: Dialog,
+    prompt: Option<Prompt>,
+    bookmarks: Bookmarks,
+    bookmarks_popup: Option<usize>,
+    #[educe(PartialEq(ignore), Hash(ignore))]
+    frecency: Frecency,
+    root: Option<PathBuf>,
+    #[educe(Debug(ignore), PartialEq(ignore), Hash(ignore))]
+    hooks: Hooks,
 }
 
 impl FileExplorer {
@@ -99,18 +161,7 @@ impl FileExplorer {
     /// assert_eq!(file_explorer.cwd().display().to_string(), "/Documents");
     /// ```
     pub fn new() -> Result<FileExplorer> {
-        let cwd = std::env::current_dir()?;
-        let files = Self::get_files(&cwd, false, None)?;
-        let file_explorer = Self {
-            cwd,
-            files,
-            show_hidden: false,
-            selected: 0,
-            theme: Theme::new(),
-            filter: None,
-        };
-
-        Ok(file_explorer)
+        FileExplorerBuilder::default().build()
     }
 
     /// Build a ratatui widget to render the file explorer. The widget can then
@@ -152,9 +203,36 @@ impl FileExplorer {
     /// - `End`: Select the last entry.
     /// - `PageUp`: Scroll the selection up.
     /// - `PageDown`: Scroll the selection down.
+    /// - `Confirm`: Same as `Right`, unless the [`DialogMode`] gives it another meaning.
+    /// - `Cancel`: Cancel the dialog (see [`DialogMode`]).
+    /// - `Char`: `j`, `k`, `h` and `l` behave like `Down`, `Up`, `Left` and `Right`, `<Space>` like `ToggleMark`
+    ///   and `/` like `Search`, unless some text is being typed.
+    /// - `Backspace`: Same as `Left`, unless some text is being typed.
     /// - `ToggleShowHidden`: Toggle between showing hidden files or not.
+    /// - `ToggleIgnored`: Toggle between hiding the files matched by `.gitignore` or not
+    ///   (see [`set_respect_ignore`](FileExplorer::set_respect_ignore), requires the `gitignore` feature).
+    /// - `GoTo`: Open the [`GoTo`](PromptKind::GoTo) prompt.
+    /// - `AddBookmark`: Open the [`AddBookmark`](PromptKind::AddBookmark) prompt.
+    /// - `Bookmarks`: Open the bookmarks popup (see [`open_bookmarks`](FileExplorer::open_bookmarks)).
+    /// - `Jump`: Open the [`Jump`](PromptKind::Jump) prompt.
+    /// - `ToggleMark`: Mark or unmark the selected entry and select the next one (see [`toggle_mark`](FileExplorer::toggle_mark)).
+    /// - `Search`: Open the [`Search`](PromptKind::Search) prompt.
+    /// - `Find`: Open the [`Find`](PromptKind::Find) prompt. While the results of a [search](FileExplorer::find)
+    ///   are shown, `Left` goes to the parent of the selected result and `Cancel` goes back to the working directory.
+    /// - `Grep`: Open the [`Grep`](PromptKind::Grep) prompt (requires the `regex` feature). `Right` on a result
+    ///   selects its file and returns [`Outcome::LineActivated`].
+    /// - `ToggleFlatten`: Toggle between flattening the listing or not (see [`set_flatten`](FileExplorer::set_flatten)).
+    /// - `ToggleDiskUsage`: Toggle between showing the sizes of the entries or not (see [`set_disk_usage`](FileExplorer::set_disk_usage)).
     /// - `None`: Do nothing.
     ///
+    /// While a [`Prompt`] is open, typed characters are sent to it, `Complete` completes it,
+    /// `Confirm` submits it and `Cancel` closes it.
+    ///
+    /// The returned [`Outcome`] tells what happened: whether the selection moved, the working directory changed,
+    /// a file was activated (`Right` on a file), or the user confirmed or cancelled the dialog.
+    /// If the [guard](FileExplorerBuilder::guard) does not allow to enter a directory, [`Outcome::Denied`] is
+    /// returned and its reason is shown by the widget (see [`message`](FileExplorer::message)).
+    ///
     /// [`Input`](crate::input::Input) implement [`From<Event>`](https://doc.rust-lang.org/stable/std/convert/trait.From.html)
     /// for `Event` from [crossterm](https://docs.rs/crossterm/latest/crossterm/event/enum.Event.html),
     /// [termion](https://docs.rs/termion/latest/termion/event/enum.Event.html)
@@ -164,6 +242,7 @@ impl FileExplorer {
     /// # Errors
     ///
     /// Will return `Err` if the new current working directory can not be listed.
+    /// The error is also passed to the [`on_error`](FileExplorerBuilder::on_error) hook.
     ///
     /// # Examples
     ///
@@ -177,13 +256,14 @@ impl FileExplorer {
     /// ```
     /// You can handle input like this:
     /// ```no_run
-    /// # use ratatui_explorer::{FileExplorer, Input};
+    /// # use ratatui_explorer::{FileExplorer, Input, Outcome};
     /// let mut file_explorer = FileExplorer::new().unwrap();
     /// file_explorer.set_show_hidden(true);
     ///
     /// /* user select `password.png` */
     ///
-    /// file_explorer.handle(Input::Down).unwrap();
+    /// let outcome = file_explorer.handle(Input::Down).unwrap();
+    /// assert_eq!(outcome, Outcome::SelectionChanged);
     /// assert_eq!(file_explorer.current().name, "resume.pdf");
     ///
     /// file_explorer.handle(Input::Up).unwrap();
@@ -196,56 +276,185 @@ impl FileExplorer {
     /// file_explorer.handle(Input::Right).unwrap();
     /// assert_eq!(file_explorer.cwd().display().to_string(), "/.git");
     /// ```
-    pub fn handle<I: Into<Input>>(&mut self, input: I) -> Result<()> {
+    pub fn handle<I: Into<Input>>(&mut self, input: I) -> Result<Outcome> {
+        let input = input.into();
+        if input != Input::None {
+            self.message = None;
+        }
+
+        self.track(|file_explorer| {
+            file_explorer.recover_cwd()?;
+            file_explorer.handle_input(input)
+        })
+    }
+
+    /// Moves to `dir` if it can be entered.
+    ///
+    /// Otherwise stays in the working directory and shows why: a denied move is reported as
+    /// [`Outcome::Denied`], and a directory that vanished is removed from the listing.
+    fn enter(&mut self, dir: PathBuf) -> Result<Option<Outcome>> {
+        match self.load_cwd(dir) {
+            Ok(()) => Ok(None),
+            Err(Error::PermissionDenied { path, reason }) => {
+                let denied = Outcome::Denied {
+                    path: path.clone(),
+                    reason: reason.clone(),
+                };
+                self.message = Some(Error::PermissionDenied { path, reason }.to_string());
+                Ok(Some(denied))
+            }
+            Err(err @ Error::NotFound(_)) => {
+                self.message = Some(err.to_string());
+                self.reload()?;
+                Ok(None)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Applies `input` to the file explorer.
+    ///
+    /// Returns `Some` if the input has an outcome that can not be deduced from the state of the file explorer.
+    fn handle_input(&mut self, input: Input) -> Result<Option<Outcome>> {
         const SCROLL_COUNT: usize = 12;
 
-        let input = input.into();
+        self.poll_find();
+        self.poll_disk_usage();
+        #[cfg(feature = "git")]
+        self.poll_git_status();
+
+        if let Some(outcome) = self.handle_bookmarks_popup(input)? {
+            return Ok(Some(outcome));
+        }
+
+        if let Some(outcome) = self.handle_prompt(input)? {
+            return Ok(Some(outcome));
+        }
+
+        if let Some(outcome) = self.handle_find(input)? {
+            return Ok(Some(outcome));
+        }
+
+        if let Some(outcome) = self.handle_dialog(input)? {
+            return Ok(Some(outcome));
+        }
 
-        match input {
+        // The listing is empty in a root directory without entries, as it has no parent entry.
+        let last = self.files.len().saturating_sub(1);
+
+        match input.as_navigation() {
             Input::Up => {
-                self.selected = self.selected.wrapping_sub(1).min(self.files.len() - 1);
+                self.selected = self.selected.wrapping_sub(1).min(last);
             }
             Input::Down => {
-                self.selected = (self.selected + 1) % self.files.len();
+                self.selected = (self.selected + 1) % self.files.len().max(1);
             }
             Input::Home => {
                 self.selected = 0;
             }
             Input::End => {
-                self.selected = self.files.len() - 1;
+                self.selected = last;
             }
             Input::PageUp => {
                 self.selected = self.selected.saturating_sub(SCROLL_COUNT);
             }
             Input::PageDown => {
-                self.selected = (self.selected + SCROLL_COUNT).min(self.files.len() - 1);
+                self.selected = (self.selected + SCROLL_COUNT).min(last);
             }
+            Input::Left if self.find.is_some() => self.leave_find_to_selected()?,
+            Input::Right if self.current_line().is_some() => return self.activate_line(),
             Input::Left => {
                 let parent = self.cwd.parent();
 
                 if let Some(parent) = parent {
                     let path = parent.to_path_buf();
-                    self.set_cwd(path)?;
+                    return self.enter(path);
                 }
             }
             Input::Right => {
-                if self.files[self.selected].path.is_dir() {
-                    let path = self.files.swap_remove(self.selected).path;
-                    self.set_cwd(path)?;
+                let Some(file) = self.try_current() else {
+                    return Ok(None);
+                };
+                if file.is_dir {
+                    let path = file.path.clone();
+                    return self.enter(path);
+                } else {
+                    return Ok(Some(Outcome::FileActivated(file.clone())));
                 }
             }
-            Input::ToggleShowHidden => self.set_show_hidden(!self.show_hidden)?,
-            Input::None => (),
+            Input::ToggleShowHidden => {
+                self.show_hidden = !self.show_hidden;
+                self.reload()?;
+            }
+            #[cfg(feature = "gitignore")]
+            Input::ToggleIgnored => {
+                self.respect_ignore = !self.respect_ignore;
+                self.reload()?;
+            }
+            Input::GoTo => self.open_prompt(PromptKind::GoTo),
+            Input::AddBookmark => self.open_prompt(PromptKind::AddBookmark),
+            Input::Bookmarks => self.open_bookmarks(),
+            Input::Jump => self.open_prompt(PromptKind::Jump),
+            Input::ToggleMark => {
+                self.toggle_mark();
+                self.selected = (self.selected + 1).min(last);
+            }
+            Input::Search => self.open_prompt(PromptKind::Search),
+            Input::Find => self.open_prompt(PromptKind::Find),
+            Input::ToggleFlatten => {
+                self.flatten = !self.flatten;
+                self.reload()?;
+            }
+            Input::ToggleDiskUsage => {
+                self.disk_usage = match self.disk_usage {
+                    Some(_) => None,
+                    None => Some(DiskUsage::default()),
+                };
+                self.reload()?;
+            }
+            #[cfg(feature = "regex")]
+            Input::Grep => self.open_prompt(PromptKind::Grep),
+            #[cfg(not(feature = "gitignore"))]
+            Input::ToggleIgnored => (),
+            #[cfg(not(feature = "regex"))]
+            Input::Grep => (),
+            Input::Confirm
+            | Input::Cancel
+            | Input::Char(_)
+            | Input::Backspace
+            | Input::Complete
+            | Input::Delete
+            | Input::SwitchPane
+            | Input::NewTab
+            | Input::CloseTab
+            | Input::NextTab
+            | Input::PreviousTab
+            | Input::DuplicateTab
+            | Input::None => (),
         }
 
-        Ok(())
+        if matches!(
+            input,
+            Input::Up
+                | Input::Down
+                | Input::Home
+                | Input::End
+                | Input::PageUp
+                | Input::PageDown
+                | Input::ToggleMark
+        ) {
+            self.sync_filename();
+        }
+
+        Ok(None)
     }
 
     /// Sets the current working directory of the file explorer.
     ///
     /// # Errors
     ///
-    /// Will return `Err` if the directory `cwd` can not be listed.
+    /// Will return [`Error::NotFound`] if `cwd` does not exist, [`Error::PermissionDenied`] if it can not be entered
+    /// (including when the [guard](FileExplorerBuilder::guard) does not allow it) or [`Error::Io`] if it can not be listed.
     ///
     /// # Examples
     ///
@@ -259,14 +468,114 @@ impl FileExplorer {
     #[inline]
     pub fn set_cwd<P: Into<PathBuf>>(&mut self, cwd: P) -> Result<()> {
         let cwd = cwd.into();
-        self.files = Self::get_files(&cwd, self.show_hidden, self.filter.as_ref())?;
+        self.track(|file_explorer| file_explorer.load_cwd(cwd).map(|()| None))?;
+
+        Ok(())
+    }
+
+    /// Lists the working directory again, eg. after its content was changed by the application.
+    ///
+    /// The first entry is selected. If a [search](FileExplorer::find) is shown, it is started again.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the working directory can not be listed.
+    #[inline]
+    pub fn refresh(&mut self) -> Result<()> {
+        self.track(|file_explorer| file_explorer.reload().map(|()| None))?;
+
+        Ok(())
+    }
+
+    /// Moves to `dir` like entering it from the listing does, and notifies the hooks.
+    pub(crate) fn go_to(&mut self, dir: PathBuf) -> Result<Outcome> {
+        self.track(|file_explorer| file_explorer.enter(dir))
+    }
+
+    /// Lists `cwd` and makes it the working directory if the guard allows it, without notifying the hooks.
+    fn load_cwd(&mut self, cwd: PathBuf) -> Result<()> {
+        self.check_guard(&cwd)?;
+        check_symlink_loop(&cwd)?;
+        self.list_cwd(cwd)
+    }
+
+    /// Lists `cwd` and makes it the working directory, without consulting the guard nor notifying the hooks.
+    fn list_cwd(&mut self, cwd: PathBuf) -> Result<()> {
+        self.files = self.get_files(&cwd)?;
 
         self.cwd = cwd;
         self.selected = 0;
+        self.hovered = None;
+        self.search = None;
+        self.find = None;
+        self.scan_sizes();
+        #[cfg(feature = "git")]
+        self.scan_git_status();
 
         Ok(())
     }
 
+    /// Returns [`Error::PermissionDenied`] with the reason given by the guard if it does not allow to enter `dir`.
+    fn check_guard(&self, dir: &Path) -> Result<()> {
+        match &self.guard {
+            Some(guard) => guard(dir).map_err(|reason| Error::PermissionDenied {
+                path: dir.to_path_buf(),
+                reason,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Lists the working directory again, or starts the [search](FileExplorer::find) again if there is one,
+    /// without notifying the hooks.
+    ///
+    /// If the working directory does not exist anymore, moves to its nearest existing ancestor.
+    fn reload(&mut self) -> Result<()> {
+        if self.restart_find() {
+            return Ok(());
+        }
+
+        match self.get_files(&self.cwd) {
+            Ok(files) => {
+                self.files = files;
+                self.selected = 0;
+                self.scan_sizes();
+                #[cfg(feature = "git")]
+                self.scan_git_status();
+
+                Ok(())
+            }
+            Err(Error::NotFound(_)) => self.recover_cwd(),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Moves to the nearest existing ancestor of the working directory if it does not exist anymore,
+    /// without notifying the hooks. The vanished directory is reported in the [`message`](FileExplorer::message).
+    ///
+    /// Returns [`Error::NotFound`] if no ancestor can be entered.
+    fn recover_cwd(&mut self) -> Result<()> {
+        if self.cwd.try_exists().unwrap_or(true) {
+            return Ok(());
+        }
+
+        let vanished = Error::NotFound(self.cwd.clone());
+        let ancestor = self
+            .cwd
+            .ancestors()
+            .skip(1)
+            .find(|ancestor| ancestor.is_dir())
+            .map(Path::to_path_buf);
+
+        match ancestor.map(|ancestor| self.load_cwd(ancestor)) {
+            Some(Ok(())) => {
+                self.message = Some(vanished.to_string());
+                Ok(())
+            }
+            _ => Err(vanished),
+        }
+    }
+
     /// Same as [`set_cwd`](FileExplorer::set_cwd) but will pre-select the file in the working directory.
     ///
     /// This method set the working directory to the parent directory of the provided file and select the file in the file explorer.
@@ -293,24 +602,27 @@ impl FileExplorer {
     #[inline]
     pub fn set_working_file<P: Into<PathBuf>>(&mut self, working_file: P) -> Result<()> {
         let working_file = working_file.into();
+        self.track(|file_explorer| file_explorer.load_working_file(working_file).map(|()| None))?;
 
+        Ok(())
+    }
+
+    /// Lists the parent of `working_file` and selects it, without notifying the hooks.
+    fn load_working_file(&mut self, working_file: PathBuf) -> Result<()> {
         let cwd = working_file
             .parent()
             .map(|p| p.to_owned())
             .unwrap_or_else(|| working_file.clone());
 
-        self.files = Self::get_files(&cwd, self.show_hidden, self.filter.as_ref())?;
+        self.check_guard(&cwd)?;
+        self.list_cwd(cwd)?;
 
-        let selected_path = working_file;
-        let selected = self
+        self.selected = self
             .files
             .iter()
-            .position(|file| file.path == selected_path)
+            .position(|file| file.path == working_file)
             .unwrap_or_default();
 
-        self.cwd = cwd;
-        self.selected = selected;
-
         Ok(())
     }
 
@@ -318,7 +630,8 @@ impl FileExplorer {
     ///
     /// # Errors
     ///
-    /// Will return `Err` if the current working directory can not be listed.
+    /// Will return `Err` if the current working directory can not be listed. If it does not exist anymore,
+    /// the file explorer moves to its nearest existing ancestor instead.
     ///
     /// # Examples
     ///
@@ -340,9 +653,35 @@ impl FileExplorer {
     /// ```
     #[inline]
     pub fn set_show_hidden(&mut self, show_hidden: bool) -> Result<()> {
-        self.show_hidden = show_hidden;
-        self.files = Self::get_files(&self.cwd, show_hidden, self.filter.as_ref())?;
-        self.selected = 0;
+        self.track(|file_explorer| {
+            file_explorer.show_hidden = show_hidden;
+            file_explorer.reload().map(|()| None)
+        })?;
+
+        Ok(())
+    }
+
+    /// Sets the decoration of the displayed [names](File::name) of the files and lists the working directory again.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the current working directory can not be listed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use ratatui_explorer::{FileExplorer, NameDecoration};
+    /// let mut file_explorer = FileExplorer::new().unwrap();
+    ///
+    /// /* executables are shown as `run.sh*` and symbolic links as `latest@` */
+    /// file_explorer.set_name_decoration(NameDecoration::Classify).unwrap();
+    /// ```
+    #[inline]
+    pub fn set_name_decoration(&mut self, name_decoration: NameDecoration) -> Result<()> {
+        self.track(|file_explorer| {
+            file_explorer.name_decoration = name_decoration;
+            file_explorer.reload().map(|()| None)
+        })?;
 
         Ok(())
     }
@@ -356,7 +695,8 @@ impl FileExplorer {
     ///
     /// # Errors
     ///
-    /// Will return `Err` if the current working directory can not be listed.
+    /// Will return `Err` if the current working directory can not be listed. If it does not exist anymore,
+    /// the file explorer moves to its nearest existing ancestor instead.
     ///
     ///  # Examples:
     ///
@@ -392,9 +732,10 @@ impl FileExplorer {
         &mut self,
         f: impl Fn(File) -> Option<File> + Send + Sync + 'static,
     ) -> Result<()> {
-        self.filter = Some(Arc::new(f));
-        self.files = Self::get_files(&self.cwd, self.show_hidden, self.filter.as_ref())?;
-        self.selected = 0;
+        self.track(|file_explorer| {
+            file_explorer.filter = Some(Arc::new(f));
+            file_explorer.reload().map(|()| None)
+        })?;
 
         Ok(())
     }
@@ -403,7 +744,8 @@ impl FileExplorer {
     ///
     /// # Errors
     ///
-    /// Will return `Err` if the current working directory can not be listed.
+    /// Will return `Err` if the current working directory can not be listed. If it does not exist anymore,
+    /// the file explorer moves to its nearest existing ancestor instead.
     ///
     /// # Examples
     /// ```no_run
@@ -417,11 +759,12 @@ impl FileExplorer {
     ///
     /// /* All files and directories are shown again */
     /// ```
-    pub fn remove_filter_map(&mut self) -> Result<Option<Arc<Filter>>> {
-        let filter = self.filter.take();
-
-        self.files = Self::get_files(&self.cwd, self.show_hidden, None)?;
-        self.selected = 0;
+    pub fn remove_filter_map(&mut self) -> Result<Option<Arc<FilterMap>>> {
+        let mut filter = None;
+        self.track(|file_explorer| {
+            filter = file_explorer.filter.take();
+            file_explorer.reload().map(|()| None)
+        })?;
 
         Ok(filter)
     }
@@ -488,11 +831,21 @@ impl FileExplorer {
     #[inline]
     pub fn set_selected_idx(&mut self, selected: usize) {
         assert!(selected < self.files.len());
-        self.selected = selected;
+        if selected != self.selected {
+            self.selected = selected;
+            self.notify(&Outcome::SelectionChanged);
+        }
     }
 
     /// Returns the current file or directory selected.
     ///
+    /// # Panics
+    ///
+    /// Panics if the listing is empty. The parent directory entry (`../`) is always listed, so it only
+    /// happens in a [root directory](FileExplorerBuilder::root) with nothing to list, or when a
+    /// [filter map](FileExplorer::set_filter_map) removes `../`. Use [`try_current`](FileExplorer::try_current)
+    /// in these cases.
+    ///
     /// # Examples
     ///
     /// Suppose you have this tree file, with `passport.png` selected inside `file_explorer`:
@@ -519,6 +872,14 @@ impl FileExplorer {
         &self.files[self.selected]
     }
 
+    /// Returns the current file or directory selected, or `None` if the listing is empty (see
+    /// [`current`](FileExplorer::current)).
+    #[inline]
+    #[must_use]
+    pub fn try_current(&self) -> Option<&File> {
+        self.files.get(self.selected)
+    }
+
     /// Returns the current working directory of the file explorer.
     ///
     /// # Examples
@@ -569,6 +930,21 @@ impl FileExplorer {
         self.show_hidden
     }
 
+    /// Returns whether the symbolic links to directories are shown and entered as directories
+    /// (see [`FileExplorerBuilder::follow_dir_symlinks`]).
+    #[inline]
+    #[must_use]
+    pub const fn follow_dir_symlinks(&self) -> bool {
+        self.follow_dir_symlinks
+    }
+
+    /// Returns the decoration of the displayed names of the files (see [`set_name_decoration`](FileExplorer::set_name_decoration)).
+    #[inline]
+    #[must_use]
+    pub const fn name_decoration(&self) -> NameDecoration {
+        self.name_decoration
+    }
+
     /// Returns the a [`Vec`](https://doc.rust-lang.org/stable/std/vec/struct.Vec.html) of files and directories in the
     /// current working directory of the file explorer, plus the parent directory if it exist.
     ///
@@ -630,6 +1006,41 @@ impl FileExplorer {
         self.selected
     }
 
+    /// Returns the message to display to the user, if any (eg. why a directory could not be entered,
+    /// or that the working directory vanished).
+    ///
+    /// The message is cleared by the next call to [`handle`](FileExplorer::handle).
+    #[inline]
+    #[must_use]
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    /// Sets the index of the entry under the mouse cursor, passed to the
+    /// [row renderer](crate::Theme::with_row_renderer) of the theme.
+    ///
+    /// The file explorer does not track the mouse itself, the hovered entry is reset when the working
+    /// directory changes.
+    #[inline]
+    pub const fn set_hovered(&mut self, hovered: Option<usize>) {
+        self.hovered = hovered;
+    }
+
+    /// Returns the index of the entry under the mouse cursor, if any (see [`set_hovered`](FileExplorer::set_hovered)).
+    #[inline]
+    #[must_use]
+    pub const fn hovered(&self) -> Option<usize> {
+        self.hovered
+    }
+
+    /// Returns the root directory the file explorer can not leave, if any
+    /// (see [`FileExplorerBuilder::root`]).
+    #[inline]
+    #[must_use]
+    pub fn root(&self) -> Option<&Path> {
+        self.root.as_deref()
+    }
+
     /// Returns the theme of the file explorer.
     ///
     /// # Examples
@@ -658,45 +1069,40 @@ impl FileExplorer {
 
     /// Get the files and directories in the current working directory and set them in the file explorer.
     /// It add the parent directory at the beginning of the [`Vec`](https://doc.rust-lang.org/stable/std/vec/struct.Vec.html)
-    /// of files if it exist.
-    fn get_files(
-        working_dir: &Path,
-        show_hidden: bool,
-        filter: Option<&Arc<Filter>>,
-    ) -> Result<Vec<File>> {
-        let (mut dirs, mut none_dirs): (Vec<_>, Vec<_>) = std::fs::read_dir(working_dir)?
-            .filter_map(|entry| {
-                let entry = entry.ok()?;
-                let path = entry.path();
-                let metadata = path.metadata().ok();
-                let file_type = metadata.as_ref().map(|f| f.file_type());
-                let is_dir = file_type.is_some_and(|f| f.is_dir());
-
-                let name = entry.file_name().to_string_lossy().into_owned();
-                let name = if is_dir { format!("{name}/") } else { name };
-
-                let is_hidden = {
-                    #[cfg(unix)]
-                    {
-                        name.starts_with('.')
-                    }
-
-                    #[cfg(windows)]
-                    {
-                        use std::os::windows::fs::MetadataExt;
-                        const FILE_ATTRIBUTE_HIDDEN: u32 = 0x2;
-                        metadata.is_some_and(|f| f.file_attributes() & FILE_ATTRIBUTE_HIDDEN != 0)
-                    }
-                };
+    /// of files if it exist and is inside the root directory.
+    ///
+    /// Returns [`Error::PermissionDenied`] if `working_dir` is outside the root directory.
+    fn get_files(&self, working_dir: &Path) -> Result<Vec<File>> {
+        let filter = self.filter.as_ref();
+
+        let at_root = match &self.root {
+            Some(root) => {
+                let canonical = working_dir
+                    .canonicalize()
+                    .map_err(|err| Error::with_path(err, working_dir))?;
+                if !canonical.starts_with(root) {
+                    return Err(Error::PermissionDenied {
+                        path: working_dir.to_path_buf(),
+                        reason: format!("outside of {}", root.display()),
+                    });
+                }
+                canonical == *root
+            }
+            None => false,
+        };
 
-                let file = File {
-                    name,
-                    path,
-                    is_dir,
-                    is_hidden,
-                    file_type,
-                };
-                if !show_hidden && file.is_hidden {
+        let mut entries = self.read_entries(working_dir)?;
+        if self.flatten {
+            entries = self.flatten_entries(working_dir, entries);
+        }
+        // The statuses are read again in the background, the last ones are shown meanwhile.
+        #[cfg(feature = "git")]
+        self.git.annotate(working_dir, &mut entries);
+
+        let (mut dirs, mut none_dirs): (Vec<_>, Vec<_>) = entries
+            .into_iter()
+            .filter_map(|file| {
+                if !self.matches_filters(&file) {
                     None
                 } else if let Some(filter) = &filter {
                     filter(file)
@@ -706,18 +1112,29 @@ impl FileExplorer {
             })
             .partition(|file| file.is_dir);
 
-        dirs.sort_unstable_by(|f1, f2| f1.name.cmp(&f2.name));
-        none_dirs.sort_unstable_by(|f1, f2| f1.name.cmp(&f2.name));
+        // The paths only differ by their file name, unless the listing is flattened.
+        dirs.sort_unstable_by(|f1, f2| f1.path.cmp(&f2.path));
+        none_dirs.sort_unstable_by(|f1, f2| f1.path.cmp(&f2.path));
 
-        let files = if let Some(parent) = working_dir.parent() {
+        let files = if let Some(parent) = working_dir.parent().filter(|_| !at_root) {
             let mut files = Vec::with_capacity(1 + dirs.len() + none_dirs.len());
 
+            let file_name = OsString::from("..");
             let parent = File {
-                name: "../".to_owned(),
+                name: file::display_name(
+                    &file_name,
+                    FileKind::Directory,
+                    true,
+                    self.name_decoration,
+                ),
+                file_name,
                 path: parent.to_path_buf(),
                 is_dir: true,
                 is_hidden: false,
                 file_type: None,
+                git_status: None,
+                kind: FileKind::Directory,
+                link_target: None,
             };
             if let Some(filter) = &filter {
                 if let Some(parent) = filter(parent) {
@@ -742,6 +1159,97 @@ impl FileExplorer {
 
         Ok(files)
     }
+
+    /// Reads the entries of `dir` that are not hidden nor ignored.
+    fn read_entries(&self, dir: &Path) -> Result<Vec<File>> {
+        #[cfg(feature = "gitignore")]
+        let not_ignored = self.respect_ignore.then(|| gitignore::not_ignored(dir));
+
+        let entries = std::fs::read_dir(dir)
+            .map_err(|err| Error::with_path(err, dir))?
+            .filter_map(|entry| {
+                let entry = entry.ok()?;
+                let path = entry.path();
+                #[cfg(feature = "gitignore")]
+                if not_ignored
+                    .as_ref()
+                    .is_some_and(|not_ignored| !not_ignored.contains(&path))
+                {
+                    return None;
+                }
+                let file =
+                    File::from_entry(&entry, path, self.name_decoration, self.follow_dir_symlinks);
+
+                (self.show_hidden || !file.is_hidden).then_some(file)
+            })
+            .collect();
+
+        Ok(entries)
+    }
+}
+
+/// Returns [`Error::PermissionDenied`] if `dir` is reached through a symbolic link to itself or to one of its
+/// parents (eg. `/a/loop/loop` where `loop` links to `/a`), as entering it would go around in circles.
+fn check_symlink_loop(dir: &Path) -> Result<()> {
+    if dir
+        .components()
+        .any(|component| component == Component::ParentDir)
+    {
+        return Ok(());
+    }
+    // An error is reported when listing the directory instead.
+    let Ok(canonical) = dir.canonicalize() else {
+        return Ok(());
+    };
+
+    if dir
+        .ancestors()
+        .skip(1)
+        .filter_map(|ancestor| ancestor.canonicalize().ok())
+        .any(|ancestor| ancestor == canonical)
+    {
+        return Err(Error::PermissionDenied {
+            path: dir.to_path_buf(),
+            reason: "symbolic link loop".to_owned(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Returns `$XDG_DATA_HOME`, or `~/.local/share` if it is not set.
+fn data_home() -> Option<PathBuf> {
+    std::env::var_os("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        .filter(|path| path.is_absolute())
+        .or_else(|| std::env::home_dir().map(|home| home.join(".local/share")))
+}
+
+/// Replaces the content of the file at `path` with `content`, creating its parent directories.
+///
+/// The content is written to a temporary file next to it, then renamed over it: readers and concurrent writers
+/// see either the old or the new content, never a truncated file.
+fn write_atomically(path: &Path, content: &str) -> std::io::Result<()> {
+    static COUNT: AtomicUsize = AtomicUsize::new(0);
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let mut tmp_name = OsString::from(".");
+    tmp_name.push(path.file_name().unwrap_or_default());
+    tmp_name.push(format!(
+        ".{}.{}.tmp",
+        std::process::id(),
+        COUNT.fetch_add(1, Ordering::Relaxed)
+    ));
+    let tmp_path = path.with_file_name(tmp_name);
+
+    std::fs::write(&tmp_path, content)
+        .and_then(|()| std::fs::rename(&tmp_path, path))
+        .inspect_err(|_| {
+            let _ = std::fs::remove_file(&tmp_path);
+        })
 }
 
 #[cfg(test)]
@@ -786,6 +1294,35 @@ mod tests {
         is_sync::<FileExplorer>();
     }
 
+    #[test]
+    fn test_handle_reports_outcome() -> Result<()> {
+        let root = build_tmp_file_system()?;
+        let documents_path = root.path().join("Documents");
+
+        let mut explorer = FileExplorerBuilder::build_with_working_file(&documents_path)?;
+        assert_eq!(explorer.handle(Input::Home)?, Outcome::SelectionChanged);
+        assert_eq!(explorer.handle(Input::Home)?, Outcome::Nothing);
+
+        explorer.handle(Input::End)?;
+        assert_eq!(
+            explorer.handle(Input::Right)?,
+            Outcome::DirectoryChanged {
+                from: root.path().to_path_buf(),
+                to: documents_path.clone(),
+            }
+        );
+
+        explorer.handle(Input::End)?;
+        let resume = explorer.current().clone();
+        assert_eq!(
+            explorer.handle(Input::Right)?,
+            Outcome::FileActivated(resume)
+        );
+        assert_eq!(*explorer.cwd(), documents_path);
+
+        Ok(())
+    }
+
     #[test]
     fn test_set_cwd_does_not_change_displayed_path_on_failure() -> Result<()> {
         let tmp_dir = TempDir::new()?;
@@ -802,6 +1339,118 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_recover_when_cwd_vanishes() -> Result<()> {
+        let root = build_tmp_file_system()?;
+        let nested_path = root.path().join("Documents/nested");
+        fs::create_dir(&nested_path)?;
+
+        let mut explorer = FileExplorerBuilder::build_with_working_dir(&nested_path)?;
+        fs::remove_dir_all(root.path().join("Documents"))?;
+
+        explorer.set_show_hidden(true)?;
+        assert_eq!(explorer.cwd(), root.path());
+        assert_eq!(
+            explorer.message(),
+            Some(format!("{} does not exist", nested_path.display()).as_str())
+        );
+
+        let err = explorer.set_cwd(&nested_path).unwrap_err();
+        assert!(matches!(err, Error::NotFound(path) if path == nested_path));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_entering_vanished_directory_stays_put() -> Result<()> {
+        let root = build_tmp_file_system()?;
+        let documents_path = root.path().join("Documents");
+
+        let mut explorer = FileExplorerBuilder::build_with_working_dir(root.path())?;
+        let idx = explorer
+            .files()
+            .iter()
+            .position(|file| file.path == documents_path)
+            .unwrap();
+        explorer.set_selected_idx(idx);
+        fs::remove_dir_all(&documents_path)?;
+
+        explorer.handle(Input::Right)?;
+        assert_eq!(explorer.cwd(), root.path());
+        assert!(explorer.message().is_some());
+        assert!(
+            explorer
+                .files()
+                .iter()
+                .all(|file| file.path != documents_path)
+        );
+
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_symlinks() -> Result<()> {
+        use std::os::unix::fs::symlink;
+
+        let root = build_tmp_file_system()?;
+        symlink(root.path().join("Documents"), root.path().join("docs"))?;
+        symlink(root.path().join("missing"), root.path().join("broken"))?;
+        symlink(root.path(), root.path().join("loop"))?;
+
+        let mut explorer = FileExplorerBuilder::build_with_working_dir(root.path())?;
+        let links: Vec<_> = explorer
+            .files()
+            .iter()
+            .map(|file| {
+                (
+                    file.name.as_str(),
+                    file.is_symlink(),
+                    file.is_broken_symlink(),
+                    file.link_target.clone(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            links,
+            [
+                ("../", false, false, None),
+                ("Documents/", false, false, None),
+                ("docs/", true, false, Some(root.path().join("Documents"))),
+                ("loop/", true, false, Some(root.path().to_path_buf())),
+                ("broken", true, true, Some(root.path().join("missing"))),
+            ]
+        );
+
+        explorer.set_selected_idx(2);
+        explorer.handle(Input::Right)?;
+        assert_eq!(explorer.cwd(), &root.path().join("docs"));
+
+        explorer.set_cwd(root.path())?;
+        explorer.set_selected_idx(3);
+        let outcome = explorer.handle(Input::Right)?;
+        assert_eq!(
+            outcome,
+            Outcome::Denied {
+                path: root.path().join("loop"),
+                reason: "symbolic link loop".to_owned(),
+            }
+        );
+        assert_eq!(explorer.cwd(), root.path());
+
+        let mut explorer = FileExplorerBuilder::default()
+            .working_dir(root.path())
+            .follow_dir_symlinks(false)
+            .build()?;
+        assert!(!explorer.files()[2].is_dir);
+        assert_eq!(explorer.files()[2].name, "broken");
+        explorer.set_selected_idx(3);
+        let outcome = explorer.handle(Input::Right)?;
+        assert!(matches!(outcome, Outcome::FileActivated(file) if file.name == "docs"));
+
+        Ok(())
+    }
+
     #[cfg(unix)]
     #[test]
     fn test_hidden_files_are_ignored() -> Result<()> {
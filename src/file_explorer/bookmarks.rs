@@ -0,0 +1,533 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::{Error, ErrorKind, Result},
+    path::{Path, PathBuf},
+};
+
+use super::{FileExplorer, Outcome, data_home, write_atomically};
+use crate::input::Input;
+
+/// Named bookmarks of directories, optionally persisted to a file.
+///
+/// A bookmark name can be a word or a single letter used as a mark (like vim marks).
+///
+/// Several bookmarks can be opened from the same file (eg. the tabs of an [`ExplorerTabs`](crate::ExplorerTabs)):
+/// every change reads the file again before saving it, so none of them overwrites the changes of the others.
+///
+/// # File format
+///
+/// The bookmarks file is a UTF-8 text file with one bookmark per line: the name, a tab character
+/// and the absolute path of the directory. Empty lines and lines starting with `#` are ignored, so names can
+/// not start with `#`.
+///
+/// ```plaintext
+/// # ratatui-explorer bookmarks
+/// d<TAB>/home/me/Documents
+/// projects<TAB>/home/me/dev
+/// ```
+///
+/// # Examples
+///
+/// ```no_run
+/// # use ratatui_explorer::{Bookmarks, FileExplorerBuilder};
+/// let file_explorer = FileExplorerBuilder::default()
+///     .bookmarks(Bookmarks::open_default().unwrap())
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Bookmarks {
+    path: Option<PathBuf>,
+    entries: BTreeMap<String, PathBuf>,
+}
+
+impl Bookmarks {
+    /// Creates empty bookmarks that are not persisted.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads the bookmarks from the file at `path`. Every change is then saved to this file.
+    ///
+    /// The file does not have to exist, it is created on the first change.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the file exists but can not be read or is not valid UTF-8.
+    pub fn open<P: Into<PathBuf>>(path: P) -> Result<Self> {
+        let mut bookmarks = Self {
+            path: Some(path.into()),
+            entries: BTreeMap::new(),
+        };
+        bookmarks.reload()?;
+
+        Ok(bookmarks)
+    }
+
+    /// Loads the bookmarks from their file again, eg. after another instance changed them. Does nothing if
+    /// the bookmarks are not persisted.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the file exists but can not be read or is not valid UTF-8.
+    pub fn reload(&mut self) -> Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+
+        self.entries = match fs::read_to_string(path) {
+            Ok(content) => Self::parse(&content),
+            Err(err) if err.kind() == ErrorKind::NotFound => BTreeMap::new(),
+            Err(err) => return Err(err),
+        };
+
+        Ok(())
+    }
+
+    /// Loads the bookmarks from the file at [`default_path`](Bookmarks::default_path).
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if no home directory can be found or if the file can not be read.
+    pub fn open_default() -> Result<Self> {
+        let path = Self::default_path()
+            .ok_or_else(|| Error::new(ErrorKind::NotFound, "no data directory found"))?;
+        Self::open(path)
+    }
+
+    /// Returns the default bookmarks file: `$XDG_DATA_HOME/ratatui-explorer/bookmarks`,
+    /// or `~/.local/share/ratatui-explorer/bookmarks` if `$XDG_DATA_HOME` is not set.
+    #[must_use]
+    pub fn default_path() -> Option<PathBuf> {
+        data_home().map(|data_home| data_home.join("ratatui-explorer").join("bookmarks"))
+    }
+
+    /// Returns the file the bookmarks are saved to, if any.
+    #[inline]
+    #[must_use]
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// Returns the directory bookmarked under `name`.
+    #[inline]
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&Path> {
+        self.entries.get(name).map(PathBuf::as_path)
+    }
+
+    /// Returns an iterator over the bookmarks, sorted by name.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Path)> {
+        self.entries
+            .iter()
+            .map(|(name, path)| (name.as_str(), path.as_path()))
+    }
+
+    /// Returns the number of bookmarks.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if there is no bookmark.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Bookmarks `path` under `name` and saves the bookmarks, after [reloading](Bookmarks::reload) them.
+    /// Returns the path previously bookmarked under `name`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if `name` is empty, starts with `#` or contains a tab or a newline, if `path` is not
+    /// valid UTF-8, or if the bookmarks can not be reloaded or saved.
+    pub fn insert<S: Into<String>, P: Into<PathBuf>>(
+        &mut self,
+        name: S,
+        path: P,
+    ) -> Result<Option<PathBuf>> {
+        let name = name.into();
+        // A name starting with `#` would be read back as a comment.
+        if name.is_empty() || name.starts_with('#') || name.contains(['\t', '\n', '\r']) {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("invalid bookmark name {name:?}"),
+            ));
+        }
+
+        let path = path.into();
+        Self::encode_path(&path)?;
+
+        self.reload()?;
+        let previous = self.entries.insert(name, path);
+        self.save()?;
+
+        Ok(previous)
+    }
+
+    /// Removes the bookmark `name` and saves the bookmarks, after [reloading](Bookmarks::reload) them.
+    /// Returns the path it was bookmarking.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the bookmarks can not be reloaded or saved.
+    pub fn remove(&mut self, name: &str) -> Result<Option<PathBuf>> {
+        self.reload()?;
+        let previous = self.entries.remove(name);
+        if previous.is_some() {
+            self.save()?;
+        }
+
+        Ok(previous)
+    }
+
+    /// Saves the bookmarks to their file. Does nothing if the bookmarks are not persisted.
+    ///
+    /// The file is replaced at once, so a crash or another instance saving at the same time can not leave it
+    /// truncated.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the file or its parent directories can not be written, or if a path is not valid UTF-8.
+    pub fn save(&self) -> Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+
+        let mut content = String::from("# ratatui-explorer bookmarks\n");
+        for (name, path) in &self.entries {
+            content.push_str(name);
+            content.push('\t');
+            content.push_str(Self::encode_path(path)?);
+            content.push('\n');
+        }
+
+        write_atomically(path, &content)
+    }
+
+    /// Returns `path` as it is written in the bookmarks file, which only holds UTF-8 paths.
+    fn encode_path(path: &Path) -> Result<&str> {
+        path.to_str().ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("bookmarked path {} is not valid UTF-8", path.display()),
+            )
+        })
+    }
+
+    fn parse(content: &str) -> BTreeMap<String, PathBuf> {
+        content
+            .lines()
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(|line| line.split_once('\t'))
+            .map(|(name, path)| (name.to_owned(), PathBuf::from(path)))
+            .collect()
+    }
+}
+
+impl FileExplorer {
+    /// Bookmarks the current working directory under `name`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the name is invalid or the bookmarks can not be saved (see [`Bookmarks::insert`]).
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use ratatui_explorer::FileExplorer;
+    /// let mut file_explorer = FileExplorer::new().unwrap();
+    ///
+    /// file_explorer.add_bookmark("d").unwrap();
+    /// file_explorer.set_cwd("/").unwrap();
+    ///
+    /// file_explorer.jump_to_bookmark("d").unwrap();
+    /// ```
+    pub fn add_bookmark<S: Into<String>>(&mut self, name: S) -> crate::Result<()> {
+        let cwd = self.cwd.clone();
+        self.bookmarks.insert(name, cwd)?;
+
+        Ok(())
+    }
+
+    /// Removes the bookmark `name` and returns the directory it was bookmarking.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the bookmarks can not be saved.
+    pub fn remove_bookmark(&mut self, name: &str) -> crate::Result<Option<PathBuf>> {
+        let removed = self.bookmarks.remove(name)?;
+
+        if let Some(selected) = self.bookmarks_popup {
+            self.bookmarks_popup = Some(selected.min(self.bookmarks.len().saturating_sub(1)));
+        }
+
+        Ok(removed)
+    }
+
+    /// Sets the working directory to the directory bookmarked under `name`.
+    ///
+    /// # Errors
+    ///
+    /// Will return [`Error::NotFound`](crate::Error::NotFound) if there is no bookmark `name` (with `name` as
+    /// path) or if its directory does not exist anymore, and `Err` if its directory can not be listed.
+    pub fn jump_to_bookmark(&mut self, name: &str) -> crate::Result<()> {
+        let path = self
+            .bookmarks
+            .get(name)
+            .ok_or_else(|| crate::Error::NotFound(PathBuf::from(name)))?
+            .to_path_buf();
+
+        self.set_cwd(path)
+    }
+
+    /// Returns the bookmarks of the file explorer.
+    #[inline]
+    #[must_use]
+    pub const fn bookmarks(&self) -> &Bookmarks {
+        &self.bookmarks
+    }
+
+    /// Opens the bookmarks popup, listing the bookmarks over the file explorer. The bookmarks are
+    /// [reloaded](Bookmarks::reload) first, to show the ones added by other instances.
+    ///
+    /// While the popup is open, [`FileExplorer::handle`] moves the selection inside it,
+    /// [`Input::Confirm`] and [`Input::Right`] jump to the selected bookmark, typing the name of a
+    /// single letter bookmark jumps to it, [`Input::Delete`] removes the selected bookmark and
+    /// [`Input::Cancel`] closes the popup. Jumping to a bookmark behaves like entering a directory: it is
+    /// reported as [`Outcome::Denied`] if the guard does not allow it, and a message is shown if the directory
+    /// does not exist anymore.
+    #[inline]
+    pub fn open_bookmarks(&mut self) {
+        if let Err(err) = self.bookmarks.reload() {
+            self.message = Some(err.to_string());
+        }
+        self.bookmarks_popup = Some(0);
+    }
+
+    /// Closes the bookmarks popup.
+    #[inline]
+    pub fn close_bookmarks(&mut self) {
+        self.bookmarks_popup = None;
+    }
+
+    /// Returns the index of the selected bookmark if the bookmarks popup is open.
+    #[inline]
+    #[must_use]
+    pub const fn bookmarks_popup(&self) -> Option<usize> {
+        self.bookmarks_popup
+    }
+
+    /// Handles `input` if the bookmarks popup is open.
+    ///
+    /// Returns `None` if the popup is not open.
+    pub(super) fn handle_bookmarks_popup(
+        &mut self,
+        input: Input,
+    ) -> crate::Result<Option<Outcome>> {
+        let Some(selected) = self.bookmarks_popup else {
+            return Ok(None);
+        };
+        let len = self.bookmarks.len();
+
+        let selected_name = self
+            .bookmarks
+            .iter()
+            .nth(selected)
+            .map(|(name, _)| name.to_owned());
+
+        let name = match input {
+            Input::Char(c) if self.bookmarks.get(&c.to_string()).is_some() => Some(c.to_string()),
+            Input::Confirm | Input::Right => selected_name,
+            Input::Delete => {
+                if let Some(name) = selected_name {
+                    self.remove_bookmark(&name)?;
+                }
+                None
+            }
+            Input::Cancel => {
+                self.bookmarks_popup = None;
+                None
+            }
+            input => {
+                let selected = match input.as_navigation() {
+                    Input::Up => selected.checked_sub(1).unwrap_or(len.saturating_sub(1)),
+                    Input::Down if len > 0 => (selected + 1) % len,
+                    Input::Home => 0,
+                    Input::End => len.saturating_sub(1),
+                    _ => selected,
+                };
+                self.bookmarks_popup = Some(selected);
+                None
+            }
+        };
+
+        if let Some(path) = name.and_then(|name| self.bookmarks.get(&name).map(Path::to_path_buf)) {
+            self.bookmarks_popup = None;
+            // Like navigating: a denied or vanished directory is reported without failing.
+            return Ok(Some(self.enter(path)?.unwrap_or(Outcome::Nothing)));
+        }
+
+        Ok(Some(Outcome::Nothing))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use tempfile::TempDir;
+
+    use crate::FileExplorerBuilder;
+
+    #[test]
+    fn test_bookmarks_are_persisted() -> Result<()> {
+        let tmp_dir = TempDir::new()?;
+        let path = tmp_dir.path().join("data/bookmarks");
+
+        let mut bookmarks = Bookmarks::open(&path)?;
+        assert!(bookmarks.is_empty());
+
+        bookmarks.insert("d", "/home/me/Documents")?;
+        bookmarks.insert("projects", "/home/me/dev")?;
+        bookmarks.remove("d")?;
+        assert!(bookmarks.insert("", "/").is_err());
+
+        let content = fs::read_to_string(&path)?;
+        assert_eq!(
+            content,
+            "# ratatui-explorer bookmarks\nprojects\t/home/me/dev\n"
+        );
+        assert_eq!(Bookmarks::open(&path)?, bookmarks);
+
+        // The file is replaced at once, through a temporary file that does not stay behind.
+        assert_eq!(fs::read_dir(tmp_dir.path().join("data"))?.count(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_names_read_as_comments_are_rejected() -> Result<()> {
+        let tmp_dir = TempDir::new()?;
+        let path = tmp_dir.path().join("bookmarks");
+
+        let mut bookmarks = Bookmarks::open(&path)?;
+        assert!(bookmarks.insert("#tag", "/home/me/tags").is_err());
+        bookmarks.insert("a#b", "/home/me/sharp")?;
+        assert_eq!(Bookmarks::open(&path)?, bookmarks);
+        assert_eq!(bookmarks.len(), 1);
+
+        #[cfg(unix)]
+        {
+            use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
+
+            let invalid = Path::new("/home/me").join(OsStr::from_bytes(b"caf\xe9"));
+            assert!(bookmarks.insert("cafe", invalid).is_err());
+            assert_eq!(Bookmarks::open(&path)?, bookmarks);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_and_jump_to_bookmark_with_inputs() -> Result<()> {
+        let tmp_dir = TempDir::new()?;
+        let documents_path = tmp_dir.path().join("Documents");
+        fs::create_dir(&documents_path)?;
+
+        let mut explorer = FileExplorerBuilder::build_with_working_dir(&documents_path)?;
+        explorer.handle(Input::AddBookmark)?;
+        explorer.handle(Input::Char('d'))?;
+        explorer.handle(Input::Confirm)?;
+        assert_eq!(
+            explorer.bookmarks().get("d"),
+            Some(documents_path.as_path())
+        );
+
+        explorer.set_cwd(tmp_dir.path())?;
+        explorer.handle(Input::Bookmarks)?;
+        assert_eq!(explorer.bookmarks_popup(), Some(0));
+
+        assert_eq!(
+            explorer.handle(Input::Char('d'))?,
+            Outcome::DirectoryChanged {
+                from: tmp_dir.path().to_path_buf(),
+                to: documents_path,
+            }
+        );
+        assert_eq!(explorer.bookmarks_popup(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_delete_bookmark_from_popup() -> Result<()> {
+        let tmp_dir = TempDir::new()?;
+
+        let mut explorer = FileExplorerBuilder::build_with_working_dir(tmp_dir.path())?;
+        explorer.add_bookmark("a")?;
+        explorer.add_bookmark("b")?;
+
+        explorer.open_bookmarks();
+        explorer.handle(Input::End)?;
+        explorer.handle(Input::Delete)?;
+
+        assert_eq!(explorer.bookmarks().len(), 1);
+        assert_eq!(explorer.bookmarks_popup(), Some(0));
+
+        explorer.handle(Input::Cancel)?;
+        assert_eq!(explorer.bookmarks_popup(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_unavailable_bookmarks_are_reported() -> Result<()> {
+        let tmp_dir = TempDir::new()?;
+        let private_path = tmp_dir.path().join("private");
+        let gone_path = tmp_dir.path().join("gone");
+        fs::create_dir(&private_path)?;
+        fs::create_dir(&gone_path)?;
+
+        let denied_path = private_path.clone();
+        let mut explorer = FileExplorerBuilder::default()
+            .working_dir(tmp_dir.path())
+            .guard(move |dir| {
+                if dir == denied_path {
+                    Err("private".to_owned())
+                } else {
+                    Ok(())
+                }
+            })
+            .build()?;
+        explorer.bookmarks.insert("g", &gone_path)?;
+        explorer.bookmarks.insert("p", &private_path)?;
+        fs::remove_dir(&gone_path)?;
+
+        explorer.open_bookmarks();
+        assert_eq!(explorer.handle(Input::Char('g'))?, Outcome::Nothing);
+        assert_eq!(
+            explorer.message(),
+            Some(format!("{} does not exist", gone_path.display()).as_str())
+        );
+
+        explorer.open_bookmarks();
+        assert!(matches!(
+            explorer.handle(Input::Char('p'))?,
+            Outcome::Denied { path, .. } if path == private_path
+        ));
+        assert_eq!(explorer.cwd(), tmp_dir.path());
+
+        assert!(matches!(
+            explorer.jump_to_bookmark("x"),
+            Err(crate::Error::NotFound(path)) if path == Path::new("x")
+        ));
+
+        Ok(())
+    }
+}
@@ -1,7 +1,15 @@
-use std::{io::Result, path::PathBuf, sync::Arc};
+use std::{
+    collections::BTreeSet,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
-use super::{File, FileExplorer, Filter};
-use crate::Theme;
+use super::flatten::DEFAULT_FLATTEN_DEPTH;
+use super::{
+    Bookmarks, Dialog, DialogMode, File, FileExplorer, Filter, FilterMap, Frecency, Guard, Hooks,
+    NameDecoration, NamedFilter,
+};
+use crate::{Error, Result, Theme};
 
 /// Builder for creating a [`FileExplorer`](FileExplorer).
 ///
@@ -12,9 +20,27 @@ pub struct FileExplorerBuilder {
     cwd: Option<PathBuf>,
     theme: Option<Theme>,
     show_hidden: bool,
+    #[cfg(feature = "gitignore")]
+    respect_ignore: bool,
+    name_decoration: NameDecoration,
+    dont_follow_dir_symlinks: bool,
+    flatten: bool,
+    flatten_depth: Option<usize>,
     #[educe(Debug(ignore), PartialEq(ignore), Hash(ignore))]
-    filter: Option<Arc<Filter>>,
+    filter: Option<Arc<FilterMap>>,
+    #[educe(PartialEq(ignore), Hash(ignore))]
+    filters: Vec<NamedFilter>,
+    #[educe(Debug(ignore), PartialEq(ignore), Hash(ignore))]
+    guard: Option<Arc<Guard>>,
     custom_selected: bool,
+    paths: Option<Vec<PathBuf>>,
+    dialog_mode: DialogMode,
+    bookmarks: Bookmarks,
+    #[educe(PartialEq(ignore), Hash(ignore))]
+    frecency: Frecency,
+    root: Option<PathBuf>,
+    #[educe(Debug(ignore), PartialEq(ignore), Hash(ignore))]
+    hooks: Hooks,
 }
 
 impl FileExplorerBuilder {
@@ -75,12 +101,81 @@ impl FileExplorerBuilder {
         self.working_dir(working_file)
     }
 
+    /// Set the files listed by the `FileExplorer` instead of the entries of its working directory, which
+    /// is the base the paths are relative to and are shown relative to.
+    ///
+    /// See [`FileExplorer::show_paths`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use ratatui_explorer::FileExplorerBuilder;
+    /// let file_explorer = FileExplorerBuilder::default()
+    ///     .working_dir("/project")
+    ///     .paths(["src/main.rs", "tests/cli.rs"])
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn paths<I>(mut self, paths: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<PathBuf>,
+    {
+        self.paths = Some(paths.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Set whether to hide the files matched by `.gitignore`, `.ignore` and `.git/info/exclude`
+    /// in the `FileExplorer`. Defaults to `false`.
+    ///
+    /// See [`FileExplorer::set_respect_ignore`] for more information.
+    ///
+    /// **Note:** This method is only available when the `gitignore` feature is enabled.
+    #[cfg(feature = "gitignore")]
+    pub fn respect_ignore(mut self, respect_ignore: bool) -> Self {
+        self.respect_ignore = respect_ignore;
+        self
+    }
+
     /// Set whether to show hidden files in the `FileExplorer`. Defaults to `false`.
     pub fn show_hidden(mut self, show: bool) -> Self {
         self.show_hidden = show;
         self
     }
 
+    /// Set whether the symbolic links to directories are shown and entered as directories in the `FileExplorer`.
+    /// Defaults to `true`.
+    ///
+    /// If `false`, they are listed with the files and [`Input::Right`](crate::Input::Right) activates them
+    /// instead of entering them.
+    pub fn follow_dir_symlinks(mut self, follow: bool) -> Self {
+        self.dont_follow_dir_symlinks = !follow;
+        self
+    }
+
+    /// Set whether the listing of the `FileExplorer` is flattened. Defaults to `false`.
+    ///
+    /// See [`FileExplorer::set_flatten`] for more information.
+    pub fn flatten(mut self, flatten: bool) -> Self {
+        self.flatten = flatten;
+        self
+    }
+
+    /// Set the maximum depth of the flattened listing of the `FileExplorer`. Defaults to `4`.
+    ///
+    /// See [`FileExplorer::set_flatten_depth`] for more information.
+    pub fn flatten_depth(mut self, depth: usize) -> Self {
+        self.flatten_depth = Some(depth);
+        self
+    }
+
+    /// Set the decoration of the displayed names of the files in the `FileExplorer`.
+    /// Defaults to [`NameDecoration::TrailingSlash`].
+    pub fn name_decoration(mut self, name_decoration: NameDecoration) -> Self {
+        self.name_decoration = name_decoration;
+        self
+    }
+
     /// Set a filter and map for the `FileExplorer`.
     ///
     /// If not set, all files are shown. Hidden files are filtered **before** this
@@ -102,6 +197,66 @@ impl FileExplorerBuilder {
         self
     }
 
+    /// Add an enabled [`Filter`] named `name` to the `FileExplorer`, replacing the filter with the same name if any.
+    ///
+    /// Filters can later be enabled and disabled with [`FileExplorer::set_filter_enabled`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use ratatui_explorer::{FileExplorerBuilder, Filter};
+    /// let file_explorer = FileExplorerBuilder::default()
+    ///     .filter("rust", Filter::extension(["rs"]))
+    ///     .filter("small", Filter::size(..1 << 10))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// /* Only small Rust files are shown */
+    /// ```
+    pub fn filter<S: Into<String>>(mut self, name: S, filter: Filter) -> Self {
+        let named = NamedFilter::new(name.into(), filter);
+        match self
+            .filters
+            .iter_mut()
+            .find(|other| other.name == named.name)
+        {
+            Some(other) => *other = named,
+            None => self.filters.push(named),
+        }
+        self
+    }
+
+    /// Set a guard deciding whether a directory can be entered.
+    ///
+    /// The guard is consulted before changing the working directory, by [`handle`](FileExplorer::handle)
+    /// (`Left` and `Right`), [`set_cwd`](FileExplorer::set_cwd) and every other method changing it.
+    /// Unlike a [`filter_map`](FileExplorerBuilder::filter_map), denied directories are still listed.
+    /// When the user is denied, `handle` returns [`Outcome::Denied`](crate::Outcome::Denied) and the widget
+    /// shows the reason.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use ratatui_explorer::FileExplorerBuilder;
+    /// let file_explorer = FileExplorerBuilder::default()
+    ///     .guard(|path| {
+    ///         if path.starts_with("/mnt/archive") {
+    ///             Err("The archive is too big to be browsed".to_owned())
+    ///         } else {
+    ///             Ok(())
+    ///         }
+    ///     })
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn guard(
+        mut self,
+        f: impl Fn(&Path) -> std::result::Result<(), String> + Send + Sync + 'static,
+    ) -> Self {
+        self.guard = Some(Arc::new(f));
+        self
+    }
+
     /// Set the theme for the `FileExplorer`.
     /// If not set, it defaults to [`Theme::new`](Theme::new).
     ///
@@ -119,12 +274,162 @@ impl FileExplorerBuilder {
         self
     }
 
+    /// Set the dialog mode of the `FileExplorer`.
+    /// If not set, it defaults to [`DialogMode::Browse`](DialogMode::Browse).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use ratatui_explorer::{DialogMode, FileExplorerBuilder};
+    /// let file_explorer = FileExplorerBuilder::default()
+    ///     .dialog_mode(DialogMode::OpenDirectory)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn dialog_mode(mut self, dialog_mode: DialogMode) -> Self {
+        self.dialog_mode = dialog_mode;
+        self
+    }
+
+    /// Set a root directory the `FileExplorer` can not leave.
+    ///
+    /// Navigating, [`set_cwd`](FileExplorer::set_cwd), [`set_working_file`](FileExplorer::set_working_file),
+    /// the prompts, the bookmarks and following symbolic links never leave the root directory:
+    /// the parent directory entry (`../`) is hidden in the root directory and trying to leave it returns
+    /// [`Error::PermissionDenied`] ([`Outcome::Denied`](crate::Outcome::Denied) when navigating).
+    ///
+    /// If no working directory is set, the `FileExplorer` starts in the root directory.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use ratatui_explorer::{FileExplorerBuilder, Input, Outcome};
+    /// let mut file_explorer = FileExplorerBuilder::default()
+    ///     .root("/home/me/workspace")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert!(matches!(file_explorer.handle(Input::Left), Ok(Outcome::Denied { .. })));
+    /// ```
+    pub fn root<P: Into<PathBuf>>(mut self, root: P) -> Self {
+        self.root = Some(root.into());
+        self
+    }
+
+    /// Set the bookmarks of the `FileExplorer`.
+    /// If not set, the `FileExplorer` starts without bookmarks and they are not persisted.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use ratatui_explorer::{Bookmarks, FileExplorerBuilder};
+    /// let file_explorer = FileExplorerBuilder::default()
+    ///     .bookmarks(Bookmarks::open("/home/me/.my_app/bookmarks").unwrap())
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn bookmarks(mut self, bookmarks: Bookmarks) -> Self {
+        self.bookmarks = bookmarks;
+        self
+    }
+
+    /// Set the frecency database of the `FileExplorer`, recording every working directory change.
+    /// If not set, the `FileExplorer` starts with an empty database that is not persisted.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use ratatui_explorer::{FileExplorerBuilder, Frecency};
+    /// let file_explorer = FileExplorerBuilder::default()
+    ///     .frecency(Frecency::open_default().unwrap())
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn frecency(mut self, frecency: Frecency) -> Self {
+        self.frecency = frecency;
+        self
+    }
+
+    /// Set a callback called when the working directory of the `FileExplorer` changes, with the
+    /// previous and the new working directory.
+    ///
+    /// The callback is called by [`handle`](FileExplorer::handle), [`set_cwd`](FileExplorer::set_cwd),
+    /// [`set_working_file`](FileExplorer::set_working_file) and every other method changing the working directory.
+    /// It is not called when the `FileExplorer` is built.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use ratatui_explorer::FileExplorerBuilder;
+    /// let file_explorer = FileExplorerBuilder::default()
+    ///     .on_cwd_change(|from, to| println!("{} -> {}", from.display(), to.display()))
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn on_cwd_change(mut self, f: impl Fn(&Path, &Path) + Send + Sync + 'static) -> Self {
+        self.hooks.on_cwd_change = Some(Arc::new(f));
+        self
+    }
+
+    /// Set a callback called with the newly selected file when the selection of the `FileExplorer` changes,
+    /// including when the working directory changes.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use ratatui_explorer::FileExplorerBuilder;
+    /// let file_explorer = FileExplorerBuilder::default()
+    ///     .on_selection_change(|file| println!("Previewing {}", file.path.display()))
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn on_selection_change(mut self, f: impl Fn(&File) + Send + Sync + 'static) -> Self {
+        self.hooks.on_selection_change = Some(Arc::new(f));
+        self
+    }
+
+    /// Set a callback called when the user activates a file that is not a directory
+    /// (see [`Outcome::FileActivated`](crate::Outcome::FileActivated)).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use ratatui_explorer::FileExplorerBuilder;
+    /// let file_explorer = FileExplorerBuilder::default()
+    ///     .on_activate(|file| println!("Opening {}", file.path.display()))
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn on_activate(mut self, f: impl Fn(&File) + Send + Sync + 'static) -> Self {
+        self.hooks.on_activate = Some(Arc::new(f));
+        self
+    }
+
+    /// Set a callback called with every error returned by the methods of the `FileExplorer`
+    /// changing its state, before the error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use ratatui_explorer::FileExplorerBuilder;
+    /// let file_explorer = FileExplorerBuilder::default()
+    ///     .on_error(|err| eprintln!("{err}"))
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn on_error(mut self, f: impl Fn(&Error) + Send + Sync + 'static) -> Self {
+        self.hooks.on_error = Some(Arc::new(f));
+        self
+    }
+
     /// Build the `FileExplorer` instance based on the provided configuration.
     ///
     /// # Errors
     ///
     /// Will return `Err` if the setted working directory can not be listed.
     ///
+    /// Will return `Err` if the root directory does not exist or if the working directory is outside of it.
+    ///
     /// Will return `Err` if **NO** working directory have been setted and current working directory can not be listed.
     /// See [`current_dir`](https://doc.rust-lang.org/stable/std/env/fn.current_dir.html) for more information.
     ///
@@ -133,22 +438,66 @@ impl FileExplorerBuilder {
         let show_hidden = self.show_hidden;
         let theme = self.theme.unwrap_or_else(Theme::new);
         let filter = self.filter;
+        let root = self
+            .root
+            .map(|root| {
+                root.canonicalize()
+                    .map_err(|err| Error::with_path(err, &root))
+            })
+            .transpose()?;
 
         let mut file_explorer = FileExplorer {
             cwd: PathBuf::new(),
             files: Vec::new(),
             show_hidden,
+            #[cfg(feature = "gitignore")]
+            respect_ignore: self.respect_ignore,
+            name_decoration: self.name_decoration,
+            follow_dir_symlinks: !self.dont_follow_dir_symlinks,
+            flatten: self.flatten,
+            flatten_depth: self.flatten_depth.unwrap_or(DEFAULT_FLATTEN_DEPTH),
             selected: 0,
             theme,
             filter,
+            filters: self.filters,
+            guard: self.guard,
+            message: None,
+            marked: BTreeSet::new(),
+            hovered: None,
+            search: None,
+            find: None,
+            disk_usage: None,
+            #[cfg(feature = "git")]
+            git: super::git::Git::default(),
+            dialog: Dialog::new(self.dialog_mode),
+            prompt: None,
+            bookmarks: self.bookmarks,
+            bookmarks_popup: None,
+            frecency: Frecency::new(),
+            root,
+            hooks: Hooks::default(),
         };
 
         if self.custom_selected {
             file_explorer.set_working_file(self.cwd.unwrap())?;
         } else {
-            file_explorer.set_cwd(self.cwd.clone().unwrap_or(std::env::current_dir()?))?;
+            let cwd = match (self.cwd, &file_explorer.root) {
+                (Some(cwd), _) => cwd,
+                (None, Some(root)) => root.clone(),
+                (None, None) => std::env::current_dir()?,
+            };
+            file_explorer.set_cwd(cwd)?;
+        }
+
+        if let Some(paths) = self.paths {
+            let base = file_explorer.cwd.clone();
+            file_explorer.show_paths(base, paths)?;
         }
 
+        // Hooks and frecency are set after the first listing so they are not called while building.
+        file_explorer.hooks = self.hooks;
+        file_explorer.frecency = self.frecency;
+
         Ok(file_explorer)
     }
 
@@ -169,6 +518,17 @@ impl FileExplorerBuilder {
     pub fn build_with_working_file<P: Into<PathBuf>>(working_dir: P) -> Result<FileExplorer> {
         Self::default().working_file(working_dir).build()
     }
+
+    /// Shortcut method to create a `FileExplorer` listing `paths`, relative to `base`.
+    /// See [`paths`](FileExplorerBuilder::paths) for more information about the listing configuration.
+    pub fn build_with_paths<P, I>(base: P, paths: I) -> Result<FileExplorer>
+    where
+        P: Into<PathBuf>,
+        I: IntoIterator,
+        I::Item: Into<PathBuf>,
+    {
+        Self::default().working_dir(base).paths(paths).build()
+    }
 }
 
 #[cfg(test)]
@@ -213,6 +573,202 @@ mod tests {
         is_sync::<FileExplorerBuilder>();
     }
 
+    #[test]
+    fn test_hooks_are_called() -> Result<()> {
+        use std::sync::Mutex;
+
+        let root = build_tmp_file_system()?;
+        let documents_path = root.path().join("Documents");
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let (cwd_events, selection_events, activate_events, error_events) = (
+            Arc::clone(&events),
+            Arc::clone(&events),
+            Arc::clone(&events),
+            Arc::clone(&events),
+        );
+
+        let mut file_explorer = FileExplorerBuilder::default()
+            .working_dir(&documents_path)
+            .on_cwd_change(move |_, to| {
+                cwd_events
+                    .lock()
+                    .unwrap()
+                    .push(format!("cwd {}", to.display()))
+            })
+            .on_selection_change(move |file| {
+                selection_events
+                    .lock()
+                    .unwrap()
+                    .push(format!("select {}", file.name))
+            })
+            .on_activate(move |file| {
+                activate_events
+                    .lock()
+                    .unwrap()
+                    .push(format!("activate {}", file.name))
+            })
+            .on_error(move |_| error_events.lock().unwrap().push("error".to_owned()))
+            .build()?;
+        assert!(events.lock().unwrap().is_empty());
+
+        file_explorer.handle(crate::Input::End)?;
+        file_explorer.handle(crate::Input::Right)?;
+        file_explorer.set_cwd(root.path())?;
+        assert!(
+            file_explorer
+                .set_cwd(root.path().join("does_not_exist"))
+                .is_err()
+        );
+
+        assert_eq!(
+            *events.lock().unwrap(),
+            [
+                "select resume.pdf".to_owned(),
+                "activate resume.pdf".to_owned(),
+                format!("cwd {}", root.path().display()),
+                "select ../".to_owned(),
+                "error".to_owned(),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_root_can_not_be_left() -> Result<()> {
+        let root = build_tmp_file_system()?;
+        let documents_path = root.path().join("Documents");
+
+        let mut file_explorer = FileExplorerBuilder::default()
+            .root(&documents_path)
+            .build()
+            .unwrap();
+        assert_eq!(*file_explorer.cwd(), documents_path);
+        assert!(file_explorer.files().iter().all(|file| file.name != "../"));
+
+        let outcome = file_explorer.handle(crate::Input::Left)?;
+        assert!(matches!(outcome, crate::Outcome::Denied { .. }));
+        assert!(file_explorer.message().is_some());
+        assert!(file_explorer.set_cwd(root.path()).is_err());
+        assert!(file_explorer.set_cwd(documents_path.join("..")).is_err());
+        assert_eq!(*file_explorer.cwd(), documents_path);
+
+        assert!(
+            FileExplorerBuilder::default()
+                .root(&documents_path)
+                .working_dir(root.path())
+                .build()
+                .is_err()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_empty_root_can_be_navigated() -> Result<()> {
+        let root = TempDir::new()?;
+
+        let mut file_explorer = FileExplorerBuilder::default()
+            .root(root.path())
+            .dialog_mode(crate::DialogMode::SaveAs {
+                default_extension: None,
+            })
+            .build()
+            .unwrap();
+        assert!(file_explorer.files().is_empty());
+        assert!(file_explorer.try_current().is_none());
+
+        for input in [
+            crate::Input::Up,
+            crate::Input::Down,
+            crate::Input::Home,
+            crate::Input::End,
+            crate::Input::PageUp,
+            crate::Input::PageDown,
+            crate::Input::Right,
+            crate::Input::ToggleMark,
+            crate::Input::ToggleDiskUsage,
+        ] {
+            file_explorer.handle(input)?;
+        }
+        assert_eq!(file_explorer.selected_idx(), 0);
+        assert!(file_explorer.try_current().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_guard_denies_directories() -> Result<()> {
+        let root = build_tmp_file_system()?;
+        let documents_path = root.path().join("Documents");
+
+        let mut file_explorer = FileExplorerBuilder::default()
+            .working_dir(root.path())
+            .guard(|path| {
+                if path.ends_with("Documents") {
+                    Err("Documents are private".to_owned())
+                } else {
+                    Ok(())
+                }
+            })
+            .build()
+            .unwrap();
+
+        let idx = file_explorer
+            .files()
+            .iter()
+            .position(|file| file.name == "Documents/")
+            .unwrap();
+        file_explorer.set_selected_idx(idx);
+
+        let outcome = file_explorer.handle(crate::Input::Right)?;
+        assert_eq!(
+            outcome,
+            crate::Outcome::Denied {
+                path: documents_path.clone(),
+                reason: "Documents are private".to_owned(),
+            }
+        );
+        assert_eq!(*file_explorer.cwd(), root.path());
+        assert_eq!(
+            file_explorer.message(),
+            Some(
+                format!(
+                    "can not enter {}: Documents are private",
+                    documents_path.display()
+                )
+                .as_str()
+            )
+        );
+
+        file_explorer.handle(crate::Input::Down)?;
+        assert_eq!(file_explorer.message(), None);
+
+        let err = file_explorer.set_cwd(&documents_path).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::PermissionDenied);
+        assert_eq!(*file_explorer.cwd(), root.path());
+
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_root_can_not_be_left_through_symlinks() -> Result<()> {
+        let root = build_tmp_file_system()?;
+        let documents_path = root.path().join("Documents");
+        std::os::unix::fs::symlink(root.path().join(".git"), documents_path.join("git"))?;
+
+        let mut file_explorer = FileExplorerBuilder::default()
+            .root(&documents_path)
+            .build()
+            .unwrap();
+
+        assert!(file_explorer.set_cwd(documents_path.join("git")).is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_working_file_correcty_set_selected_file() -> Result<()> {
         let root = build_tmp_file_system()?;
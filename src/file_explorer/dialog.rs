@@ -0,0 +1,478 @@
+use std::path::{Component, Path, PathBuf};
+
+use super::{FileExplorer, Outcome};
+use crate::{Result, input::Input};
+
+/// The kind of dialog a [`FileExplorer`] acts as.
+///
+/// The dialog mode decides what [`Input::Confirm`] and [`Input::Cancel`] mean and what
+/// [`FileExplorer::handle`] reports back with [`Outcome::Confirmed`] and [`Outcome::Cancelled`].
+///
+/// # Examples
+/// ```no_run
+/// # use ratatui_explorer::{DialogMode, FileExplorerBuilder, Input, Outcome};
+/// let mut file_explorer = FileExplorerBuilder::default()
+///     .dialog_mode(DialogMode::OpenFile)
+///     .build()
+///     .unwrap();
+///
+/// match file_explorer.handle(Input::Confirm).unwrap() {
+///     Outcome::Confirmed(paths) => println!("Opening {}", paths[0].display()),
+///     Outcome::Cancelled => println!("Nothing to open"),
+///     _ => {}
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub enum DialogMode {
+    /// Plain browsing. Confirming enters the selected directory and nothing is ever confirmed or cancelled.
+    #[default]
+    Browse,
+    /// Pick an existing file. Confirming a file returns it, confirming a directory enters it.
+    ///
+    /// If some entries are [marked](FileExplorer::toggle_mark), confirming returns the marked files instead,
+    /// and does nothing if only directories are marked.
+    OpenFile,
+    /// Pick a directory. Confirming a directory returns it instead of entering it,
+    /// [`Input::Right`] still enters it. Confirming the parent directory entry (`../`) does nothing.
+    ///
+    /// If some entries are [marked](FileExplorer::toggle_mark), confirming returns the marked directories
+    /// instead, and does nothing if only files are marked.
+    OpenDirectory,
+    /// Pick a path to save to.
+    ///
+    /// A filename input line is shown below the listing. Typed characters edit the filename and
+    /// selecting a file copies its name into it. Confirming returns the cwd joined with the filename,
+    /// after asking for confirmation if the file already exists: `y` confirms, `n` or [`Input::Cancel`] goes
+    /// back to the filename and the other inputs are ignored until one of them is pressed.
+    ///
+    /// The filename names a file of the cwd: a filename containing a path separator or naming `..` is not
+    /// confirmed, and a message tells it is invalid. Confirming the name of a directory enters it.
+    SaveAs {
+        /// Extension (without the leading dot) appended to the filename when it has none.
+        default_extension: Option<String>,
+    },
+}
+
+/// State of the dialog of a [`FileExplorer`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub(super) struct Dialog {
+    pub(super) mode: DialogMode,
+    pub(super) filename: String,
+    pub(super) overwrite: Option<PathBuf>,
+}
+
+impl Dialog {
+    pub(super) fn new(mode: DialogMode) -> Self {
+        Self {
+            mode,
+            ..Self::default()
+        }
+    }
+}
+
+impl FileExplorer {
+    /// Handles the inputs that have a dialog specific meaning.
+    ///
+    /// Returns `None` if the input should be handled as a navigation input.
+    pub(super) fn handle_dialog(&mut self, input: Input) -> Result<Option<Outcome>> {
+        // The overwrite confirmation waits for an answer, other inputs (eg. resize events) are ignored.
+        if let Some(path) = &self.dialog.overwrite {
+            let outcome = match input {
+                Input::Char('y' | 'Y') => Outcome::Confirmed(vec![path.clone()]),
+                Input::Char('n' | 'N') | Input::Cancel => Outcome::Nothing,
+                _ => return Ok(Some(Outcome::Nothing)),
+            };
+            self.dialog.overwrite = None;
+            return Ok(Some(outcome));
+        }
+
+        let outcome = match (&self.dialog.mode, input) {
+            (DialogMode::Browse, _) => None,
+            (_, Input::Cancel) => Some(Outcome::Cancelled),
+            (mode @ (DialogMode::OpenFile | DialogMode::OpenDirectory), Input::Confirm)
+                if !self.marked.is_empty() =>
+            {
+                let want_dirs = *mode == DialogMode::OpenDirectory;
+                let paths: Vec<_> = self
+                    .marked
+                    .iter()
+                    .filter(|path| path.is_dir() == want_dirs)
+                    .cloned()
+                    .collect();
+                if paths.is_empty() {
+                    Some(Outcome::Nothing)
+                } else {
+                    Some(Outcome::Confirmed(paths))
+                }
+            }
+            (DialogMode::OpenFile, Input::Confirm) => match self.try_current() {
+                Some(current) if !current.is_dir => {
+                    Some(Outcome::Confirmed(vec![current.path.clone()]))
+                }
+                _ => None,
+            },
+            (DialogMode::OpenDirectory, Input::Confirm) => match self.try_current() {
+                Some(current) if current.is_dir && !current.is_parent_entry() => {
+                    Some(Outcome::Confirmed(vec![current.path.clone()]))
+                }
+                _ => Some(Outcome::Nothing),
+            },
+            (DialogMode::SaveAs { default_extension }, Input::Confirm) => {
+                if self.dialog.filename.is_empty() {
+                    return Ok(None);
+                }
+                if !is_file_name(&self.dialog.filename) {
+                    self.message = Some(format!("invalid filename: {}", self.dialog.filename));
+                    return Ok(Some(Outcome::Nothing));
+                }
+
+                let mut path = self.cwd.join(&self.dialog.filename);
+                if let Some(extension) = default_extension
+                    && path.extension().is_none()
+                {
+                    path.set_extension(extension);
+                }
+
+                if path.is_dir() {
+                    let outcome = self.enter(path)?;
+                    if outcome.is_none() {
+                        self.dialog.filename.clear();
+                    }
+                    Some(outcome.unwrap_or(Outcome::Nothing))
+                } else if path.exists() {
+                    self.dialog.overwrite = Some(path);
+                    Some(Outcome::Nothing)
+                } else {
+                    Some(Outcome::Confirmed(vec![path]))
+                }
+            }
+            (DialogMode::SaveAs { .. }, Input::Char(c)) => {
+                self.dialog.filename.push(c);
+                Some(Outcome::Nothing)
+            }
+            (DialogMode::SaveAs { .. }, Input::Backspace) => {
+                self.dialog.filename.pop();
+                Some(Outcome::Nothing)
+            }
+            _ => None,
+        };
+
+        Ok(outcome)
+    }
+
+    /// Copies the name of the selected file into the save-as filename.
+    pub(super) fn sync_filename(&mut self) {
+        if matches!(self.dialog.mode, DialogMode::SaveAs { .. })
+            && let Some(current) = self.try_current()
+            && !current.is_dir
+        {
+            self.dialog.filename = current.file_name.to_string_lossy().into_owned();
+        }
+    }
+
+    /// Sets the dialog mode of the file explorer and resets the save-as filename.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use ratatui_explorer::{DialogMode, FileExplorer};
+    /// let mut file_explorer = FileExplorer::new().unwrap();
+    /// file_explorer.set_dialog_mode(DialogMode::SaveAs { default_extension: Some("txt".into()) });
+    /// ```
+    #[inline]
+    pub fn set_dialog_mode(&mut self, mode: DialogMode) {
+        self.dialog = Dialog::new(mode);
+    }
+
+    /// Returns the dialog mode of the file explorer.
+    #[inline]
+    #[must_use]
+    pub const fn dialog_mode(&self) -> &DialogMode {
+        &self.dialog.mode
+    }
+
+    /// Sets the filename of the save-as input line.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use ratatui_explorer::{DialogMode, FileExplorerBuilder};
+    /// let mut file_explorer = FileExplorerBuilder::default()
+    ///     .dialog_mode(DialogMode::SaveAs { default_extension: None })
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// file_explorer.set_filename("untitled.txt");
+    /// assert_eq!(file_explorer.filename(), "untitled.txt");
+    /// ```
+    #[inline]
+    pub fn set_filename<S: Into<String>>(&mut self, filename: S) {
+        self.dialog.filename = filename.into();
+        self.dialog.overwrite = None;
+    }
+
+    /// Returns the filename typed in the save-as input line.
+    #[inline]
+    #[must_use]
+    pub fn filename(&self) -> &str {
+        &self.dialog.filename
+    }
+
+    /// Returns the path waiting for an overwrite confirmation, if any.
+    #[inline]
+    #[must_use]
+    pub fn pending_overwrite(&self) -> Option<&Path> {
+        self.dialog.overwrite.as_deref()
+    }
+}
+
+/// Returns `true` if `filename` names a file of a directory: it is a single normal component.
+fn is_file_name(filename: &str) -> bool {
+    let mut components = Path::new(filename).components();
+    matches!(components.next(), Some(Component::Normal(name)) if name == filename)
+        && components.next().is_none()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::fs::{self, File};
+    use tempfile::TempDir;
+
+    use crate::FileExplorerBuilder;
+
+    /// Build this temporary file system:
+    /// ```plaintext
+    /// <unknow>
+    /// └ root
+    ///   ├── .git
+    ///   └── Documents
+    ///       ├── passport.png
+    ///       └── resume.pdf
+    /// ```
+    fn build_tmp_file_system() -> Result<TempDir> {
+        let root = TempDir::new()?;
+
+        let git_path = root.path().join(".git");
+        let documents_path = root.path().join("Documents");
+        let passport_path = root.path().join("Documents/passport.png");
+        let resume_path = root.path().join("Documents/resume.pdf");
+
+        fs::create_dir(git_path)?;
+        fs::create_dir(documents_path)?;
+        File::create(passport_path)?;
+        File::create(resume_path)?;
+
+        Ok(root)
+    }
+
+    #[test]
+    fn test_open_file_enters_dirs_and_confirms_files() -> Result<()> {
+        let root = build_tmp_file_system()?;
+        let documents_path = root.path().join("Documents");
+
+        let mut explorer = FileExplorerBuilder::default()
+            .dialog_mode(DialogMode::OpenFile)
+            .working_file(&documents_path)
+            .build()?;
+
+        assert_eq!(
+            explorer.handle(Input::Confirm)?,
+            Outcome::DirectoryChanged {
+                from: root.path().to_path_buf(),
+                to: documents_path.clone(),
+            }
+        );
+
+        explorer.handle(Input::Down)?;
+        assert_eq!(
+            explorer.handle(Input::Confirm)?,
+            Outcome::Confirmed(vec![documents_path.join("passport.png")])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_directory_confirms_dirs_instead_of_entering_them() -> Result<()> {
+        let root = build_tmp_file_system()?;
+        let documents_path = root.path().join("Documents");
+
+        let mut explorer = FileExplorerBuilder::default()
+            .dialog_mode(DialogMode::OpenDirectory)
+            .working_file(&documents_path)
+            .build()?;
+
+        assert_eq!(
+            explorer.handle(Input::Confirm)?,
+            Outcome::Confirmed(vec![documents_path.clone()])
+        );
+        assert_eq!(*explorer.cwd(), root.path());
+
+        explorer.handle(Input::Right)?;
+        assert_eq!(*explorer.cwd(), documents_path);
+
+        explorer.handle(Input::Home)?;
+        assert_eq!(explorer.current().name, "../");
+        assert_eq!(explorer.handle(Input::Confirm)?, Outcome::Nothing);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cancel_only_reported_by_dialogs() -> Result<()> {
+        let root = build_tmp_file_system()?;
+
+        let mut explorer = FileExplorerBuilder::build_with_working_dir(root.path())?;
+        assert_eq!(explorer.handle(Input::Cancel)?, Outcome::Nothing);
+
+        explorer.set_dialog_mode(DialogMode::OpenFile);
+        assert_eq!(explorer.handle(Input::Cancel)?, Outcome::Cancelled);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_save_as_appends_default_extension() -> Result<()> {
+        let root = build_tmp_file_system()?;
+
+        let mut explorer = FileExplorerBuilder::default()
+            .dialog_mode(DialogMode::SaveAs {
+                default_extension: Some("txt".to_owned()),
+            })
+            .working_dir(root.path())
+            .build()?;
+
+        for c in "jokes".chars() {
+            explorer.handle(Input::Char(c))?;
+        }
+        explorer.handle(Input::Backspace)?;
+        assert_eq!(explorer.filename(), "joke");
+
+        assert_eq!(
+            explorer.handle(Input::Confirm)?,
+            Outcome::Confirmed(vec![root.path().join("joke.txt")])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_save_as_asks_before_overwriting() -> Result<()> {
+        let root = build_tmp_file_system()?;
+        let documents_path = root.path().join("Documents");
+        let resume_path = documents_path.join("resume.pdf");
+
+        let mut explorer = FileExplorerBuilder::default()
+            .dialog_mode(DialogMode::SaveAs {
+                default_extension: None,
+            })
+            .working_dir(&documents_path)
+            .build()?;
+
+        explorer.handle(Input::End)?;
+        assert_eq!(explorer.filename(), "resume.pdf");
+
+        assert_eq!(explorer.handle(Input::Confirm)?, Outcome::Nothing);
+        assert_eq!(explorer.pending_overwrite(), Some(resume_path.as_path()));
+
+        // Events without a meaning, like resizes, keep the confirmation.
+        assert_eq!(explorer.handle(Input::None)?, Outcome::Nothing);
+        assert_eq!(explorer.handle(Input::Down)?, Outcome::Nothing);
+        assert_eq!(explorer.pending_overwrite(), Some(resume_path.as_path()));
+
+        assert_eq!(explorer.handle(Input::Char('n'))?, Outcome::Nothing);
+        assert_eq!(explorer.pending_overwrite(), None);
+
+        explorer.handle(Input::Confirm)?;
+        assert_eq!(
+            explorer.handle(Input::Char('y'))?,
+            Outcome::Confirmed(vec![resume_path])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_confirms_the_marked_entries_of_its_kind() -> Result<()> {
+        let root = build_tmp_file_system()?;
+        let documents_path = root.path().join("Documents");
+        let passport_path = documents_path.join("passport.png");
+
+        let mut explorer = FileExplorerBuilder::default()
+            .dialog_mode(DialogMode::OpenFile)
+            .working_dir(root.path())
+            .build()?;
+
+        explorer.set_marked(&documents_path, true);
+        assert_eq!(explorer.handle(Input::Confirm)?, Outcome::Nothing);
+
+        explorer.set_marked(&passport_path, true);
+        assert_eq!(
+            explorer.handle(Input::Confirm)?,
+            Outcome::Confirmed(vec![passport_path.clone()])
+        );
+
+        explorer.set_dialog_mode(DialogMode::OpenDirectory);
+        assert_eq!(
+            explorer.handle(Input::Confirm)?,
+            Outcome::Confirmed(vec![documents_path])
+        );
+
+        explorer.set_marked(root.path().join("Documents"), false);
+        assert_eq!(explorer.handle(Input::Confirm)?, Outcome::Nothing);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_save_as_stays_in_the_listed_directory() -> Result<()> {
+        let root = build_tmp_file_system()?;
+        let documents_path = root.path().join("Documents");
+
+        let guarded_path = documents_path.clone();
+        let mut explorer = FileExplorerBuilder::default()
+            .dialog_mode(DialogMode::SaveAs {
+                default_extension: None,
+            })
+            .working_dir(root.path())
+            .guard(move |dir| {
+                if dir == guarded_path {
+                    Err("private".to_owned())
+                } else {
+                    Ok(())
+                }
+            })
+            .build()?;
+
+        let outside = ["/etc/passwd", "../escape", "Documents/resume.pdf", ".."];
+        for filename in outside {
+            explorer.set_filename(filename);
+            assert_eq!(explorer.handle(Input::Confirm)?, Outcome::Nothing);
+            assert_eq!(
+                explorer.message(),
+                Some(format!("invalid filename: {filename}").as_str())
+            );
+            assert_eq!(explorer.pending_overwrite(), None);
+        }
+
+        // Confirming a directory enters it like navigating does, and the guard is consulted.
+        explorer.set_filename("Documents");
+        assert!(matches!(
+            explorer.handle(Input::Confirm)?,
+            Outcome::Denied { path, .. } if path == documents_path
+        ));
+        assert_eq!(*explorer.cwd(), root.path());
+
+        explorer.set_filename(".git");
+        assert_eq!(
+            explorer.handle(Input::Confirm)?,
+            Outcome::DirectoryChanged {
+                from: root.path().to_path_buf(),
+                to: root.path().join(".git"),
+            }
+        );
+        assert_eq!(explorer.filename(), "");
+
+        Ok(())
+    }
+}
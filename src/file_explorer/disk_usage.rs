@@ -0,0 +1,464 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+    thread,
+};
+
+use super::FileExplorer;
+use crate::Result;
+
+/// The number of entries read between two updates of the size of the directory being measured.
+const PROGRESS_INTERVAL: usize = 512;
+
+/// The state of the disk-usage mode of a [`FileExplorer`].
+#[derive(Debug, Clone, Default)]
+pub(super) struct DiskUsage {
+    /// The sizes computed so far, kept when the working directory changes.
+    cache: Arc<Cache>,
+    /// Stops the scan of the working directory when the last clone is dropped.
+    scan: Option<Arc<ScanHandle>>,
+}
+
+#[derive(Debug, Default)]
+struct Cache {
+    sizes: Mutex<HashMap<PathBuf, Size>>,
+    /// Set when a size changed since the last poll.
+    changed: AtomicBool,
+}
+
+impl Cache {
+    fn get(&self, path: &Path) -> Option<Size> {
+        self.sizes.lock().ok()?.get(path).copied()
+    }
+
+    fn insert(&self, path: &Path, size: Size) {
+        if let Ok(mut sizes) = self.sizes.lock() {
+            sizes.insert(path.to_path_buf(), size);
+            self.changed.store(true, Ordering::Relaxed);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Size {
+    bytes: u64,
+    /// `false` while the directory is being measured.
+    complete: bool,
+}
+
+#[derive(Debug, Default)]
+struct ScanState {
+    done: AtomicBool,
+    cancelled: AtomicBool,
+}
+
+#[derive(Debug)]
+struct ScanHandle(Arc<ScanState>);
+
+impl Drop for ScanHandle {
+    fn drop(&mut self) {
+        self.0.cancelled.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Measures the directories of the working directory, one after the other.
+struct Scanner<'a> {
+    cache: &'a Cache,
+    state: &'a ScanState,
+    /// The entry of the working directory being measured.
+    top: PathBuf,
+    /// The bytes counted so far for `top`.
+    counted: u64,
+    /// The entries read since the last update of the size of `top`.
+    read: usize,
+}
+
+impl Scanner<'_> {
+    fn run(cache: &Cache, state: &ScanState, entries: Vec<(PathBuf, bool)>) {
+        // The files are measured first, so that they are sized right away.
+        let (dirs, files): (Vec<_>, Vec<_>) = entries.into_iter().partition(|&(_, is_dir)| is_dir);
+
+        for (path, _) in files {
+            let bytes = fs::symlink_metadata(&path).map_or(0, |metadata| metadata.len());
+            cache.insert(
+                &path,
+                Size {
+                    bytes,
+                    complete: true,
+                },
+            );
+        }
+
+        for (path, _) in dirs {
+            let mut scanner = Scanner {
+                cache,
+                state,
+                top: path.clone(),
+                counted: 0,
+                read: 0,
+            };
+            if scanner.measure(&path).is_none() {
+                return;
+            }
+        }
+    }
+
+    /// Returns the total size of the files below `dir` and caches it, or `None` if the scan is cancelled.
+    ///
+    /// The symbolic links are not followed.
+    fn measure(&mut self, dir: &Path) -> Option<u64> {
+        if let Some(size) = self.cache.get(dir).filter(|size| size.complete) {
+            self.counted += size.bytes;
+            return Some(size.bytes);
+        }
+
+        let mut bytes = 0;
+        for entry in fs::read_dir(dir).into_iter().flatten().flatten() {
+            if self.state.cancelled.load(Ordering::Relaxed) {
+                return None;
+            }
+
+            if entry.file_type().is_ok_and(|file_type| file_type.is_dir()) {
+                bytes += self.measure(&entry.path())?;
+            } else {
+                let len = entry.metadata().map_or(0, |metadata| metadata.len());
+                bytes += len;
+                self.counted += len;
+            }
+
+            self.read += 1;
+            if self.read >= PROGRESS_INTERVAL {
+                self.read = 0;
+                self.cache.insert(
+                    &self.top,
+                    Size {
+                        bytes: self.counted,
+                        complete: false,
+                    },
+                );
+            }
+        }
+
+        self.cache.insert(
+            dir,
+            Size {
+                bytes,
+                complete: true,
+            },
+        );
+        Some(bytes)
+    }
+}
+
+impl FileExplorer {
+    /// Sets whether the disk-usage mode is enabled: the total size of each entry of the working directory is
+    /// computed, and the entries are sorted from the biggest to the smallest.
+    ///
+    /// The sizes of the directories are computed by a background thread, without following the symbolic links.
+    /// They grow as the directories are measured and the listing is sorted again by
+    /// [`poll_disk_usage`](FileExplorer::poll_disk_usage). The sizes are cached, so that going back to a directory
+    /// does not measure it again (see [`refresh_disk_usage`](FileExplorer::refresh_disk_usage)).
+    ///
+    /// The widget shows the size of each entry, its share of the working directory and a bar representing it
+    /// (see [`RowContext::size`](crate::RowContext::size)).
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the current working directory can not be listed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use ratatui_explorer::FileExplorer;
+    /// let mut file_explorer = FileExplorer::new().unwrap();
+    /// file_explorer.set_disk_usage(true).unwrap();
+    ///
+    /// loop {
+    ///     file_explorer.poll_disk_usage();
+    ///     /* draw the file explorer and handle the events */
+    /// #   break;
+    /// }
+    /// ```
+    #[inline]
+    pub fn set_disk_usage(&mut self, disk_usage: bool) -> Result<()> {
+        self.track(|file_explorer| {
+            if disk_usage == file_explorer.disk_usage.is_some() {
+                return Ok(None);
+            }
+
+            file_explorer.disk_usage = disk_usage.then(DiskUsage::default);
+            file_explorer.reload().map(|()| None)
+        })?;
+
+        Ok(())
+    }
+
+    /// Returns whether the disk-usage mode is enabled (see [`set_disk_usage`](FileExplorer::set_disk_usage)).
+    #[inline]
+    #[must_use]
+    pub const fn disk_usage(&self) -> bool {
+        self.disk_usage.is_some()
+    }
+
+    /// Returns the size of the file or directory at `path` computed by the disk-usage mode, if it is known.
+    ///
+    /// The size of a directory being measured is the size counted so far.
+    #[must_use]
+    pub fn size(&self, path: &Path) -> Option<u64> {
+        let size = self.disk_usage.as_ref()?.cache.get(path)?;
+
+        Some(size.bytes)
+    }
+
+    /// Returns the total size of the listed entries computed by the disk-usage mode, or `None` if it is disabled.
+    #[must_use]
+    pub fn total_size(&self) -> Option<u64> {
+        let disk_usage = self.disk_usage.as_ref()?;
+        let sizes = disk_usage.cache.sizes.lock().ok()?;
+
+        Some(
+            self.files
+                .iter()
+                .filter(|file| !file.is_parent_entry())
+                .filter_map(|file| sizes.get(&file.path))
+                .map(|size| size.bytes)
+                .sum(),
+        )
+    }
+
+    /// Sorts the listing again if sizes were computed since the last call. Returns `true` if they were.
+    ///
+    /// Call it regularly (eg. before drawing) while [`is_disk_usage_running`](FileExplorer::is_disk_usage_running)
+    /// returns `true`. It is also called by [`handle`](FileExplorer::handle).
+    pub fn poll_disk_usage(&mut self) -> bool {
+        let Some(disk_usage) = &self.disk_usage else {
+            return false;
+        };
+        if !disk_usage.cache.changed.swap(false, Ordering::Relaxed) {
+            return false;
+        }
+
+        self.sort_by_size();
+        true
+    }
+
+    /// Returns `true` if the sizes of the entries of the working directory are still being computed.
+    #[inline]
+    #[must_use]
+    pub fn is_disk_usage_running(&self) -> bool {
+        self.disk_usage
+            .as_ref()
+            .and_then(|disk_usage| disk_usage.scan.as_ref())
+            .is_some_and(|scan| !scan.0.done.load(Ordering::Relaxed))
+    }
+
+    /// Forgets the cached sizes and measures the entries of the working directory again.
+    pub fn refresh_disk_usage(&mut self) {
+        if self.disk_usage.is_some() {
+            self.disk_usage = Some(DiskUsage::default());
+            self.scan_sizes();
+        }
+    }
+
+    /// Measures the entries of the listing whose size is not cached and sorts it, if the disk-usage mode is enabled.
+    pub(super) fn scan_sizes(&mut self) {
+        let Some(disk_usage) = self.disk_usage.as_mut() else {
+            return;
+        };
+
+        let entries: Vec<_> = self
+            .files
+            .iter()
+            .filter(|file| !file.is_parent_entry())
+            .filter(|file| {
+                disk_usage
+                    .cache
+                    .get(&file.path)
+                    .is_none_or(|size| !size.complete)
+            })
+            .map(|file| (file.path.clone(), file.is_dir))
+            .collect();
+
+        let state = Arc::new(ScanState::default());
+        let thread_state = Arc::clone(&state);
+        let cache = Arc::clone(&disk_usage.cache);
+        thread::spawn(move || {
+            Scanner::run(&cache, &thread_state, entries);
+            thread_state.done.store(true, Ordering::Relaxed);
+        });
+        disk_usage.scan = Some(Arc::new(ScanHandle(state)));
+
+        self.sort_by_size();
+    }
+
+    /// Sorts the entries from the biggest to the smallest, keeping the parent directory first and the selected entry.
+    ///
+    /// The results of a [search](FileExplorer::find) keep the order they were found in, which their line numbers
+    /// and the results still to come follow.
+    fn sort_by_size(&mut self) {
+        let Some(disk_usage) = &self.disk_usage else {
+            return;
+        };
+        if self.find.is_some() {
+            return;
+        }
+        let Ok(sizes) = disk_usage.cache.sizes.lock() else {
+            return;
+        };
+
+        let Some(selected) = self.try_current().map(|file| file.path.clone()) else {
+            return;
+        };
+        let start = usize::from(self.files[0].is_parent_entry());
+        let bytes = |path: &Path| sizes.get(path).map_or(0, |size| size.bytes);
+        self.files[start..].sort_by(|f1, f2| {
+            bytes(&f2.path)
+                .cmp(&bytes(&f1.path))
+                .then_with(|| f1.path.cmp(&f2.path))
+        });
+
+        if let Some(idx) = self.files.iter().position(|file| file.path == selected) {
+            self.selected = idx;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, time::Duration};
+    use tempfile::TempDir;
+
+    use crate::{FileExplorer, FileExplorerBuilder, Filter, Input, Result};
+
+    /// Build this temporary file system:
+    /// ```plaintext
+    /// <unknow>
+    /// └ root
+    ///   ├── build
+    ///   │   ├── cache
+    ///   │   │   └── objects  (3000 bytes)
+    ///   │   └── app          (500 bytes)
+    ///   ├── logs
+    ///   │   └── app.log      (1000 bytes)
+    ///   └── notes.txt        (2000 bytes)
+    /// ```
+    fn build_tmp_file_system() -> Result<TempDir> {
+        let root = TempDir::new()?;
+
+        fs::create_dir_all(root.path().join("build/cache"))?;
+        fs::create_dir_all(root.path().join("logs"))?;
+        fs::write(root.path().join("build/cache/objects"), [0; 3000])?;
+        fs::write(root.path().join("build/app"), [0; 500])?;
+        fs::write(root.path().join("logs/app.log"), [0; 1000])?;
+        fs::write(root.path().join("notes.txt"), [0; 2000])?;
+
+        Ok(root)
+    }
+
+    fn wait_sizes(file_explorer: &mut FileExplorer) -> Vec<(&str, Option<u64>)> {
+        while file_explorer.is_disk_usage_running() {
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        file_explorer.poll_disk_usage();
+
+        file_explorer
+            .files()
+            .iter()
+            .map(|file| (file.name.as_str(), file_explorer.size(&file.path)))
+            .collect()
+    }
+
+    #[test]
+    fn test_entries_are_sorted_by_size() -> Result<()> {
+        let root = build_tmp_file_system()?;
+
+        let mut file_explorer = FileExplorerBuilder::build_with_working_dir(root.path())?;
+        file_explorer.handle(Input::ToggleDiskUsage)?;
+        assert!(file_explorer.disk_usage());
+        assert_eq!(
+            wait_sizes(&mut file_explorer),
+            [
+                ("../", None),
+                ("build/", Some(3500)),
+                ("notes.txt", Some(2000)),
+                ("logs/", Some(1000)),
+            ]
+        );
+        assert_eq!(file_explorer.total_size(), Some(6500));
+
+        // The selected entry stays selected when the listing is sorted again.
+        file_explorer.handle(Input::End)?;
+        fs::write(root.path().join("logs/old.log"), [0; 5000])?;
+        file_explorer.refresh_disk_usage();
+        assert_eq!(
+            wait_sizes(&mut file_explorer)[1..],
+            [
+                ("logs/", Some(6000)),
+                ("build/", Some(3500)),
+                ("notes.txt", Some(2000)),
+            ]
+        );
+        assert_eq!(file_explorer.current().name, "logs/");
+
+        // The sizes of the subdirectories were cached while measuring their parent.
+        file_explorer.set_cwd(root.path().join("build"))?;
+        assert_eq!(
+            file_explorer.size(&root.path().join("build/cache")),
+            Some(3000)
+        );
+        assert_eq!(
+            wait_sizes(&mut file_explorer),
+            [("../", None), ("cache/", Some(3000)), ("app", Some(500))]
+        );
+
+        file_explorer.set_disk_usage(false)?;
+        assert_eq!(file_explorer.total_size(), None);
+        assert_eq!(file_explorer.size(&root.path().join("build/app")), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_find_results_keep_their_order() -> Result<()> {
+        let root = build_tmp_file_system()?;
+
+        let mut file_explorer = FileExplorerBuilder::build_with_working_dir(root.path())?;
+        file_explorer.set_disk_usage(true)?;
+        wait_sizes(&mut file_explorer);
+
+        file_explorer.find(Filter::new(|file| !file.is_dir), None);
+        while file_explorer.is_find_running() {
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        file_explorer.poll_find();
+        let found: Vec<_> = file_explorer
+            .files()
+            .iter()
+            .map(|file| file.name.clone())
+            .collect();
+        assert_eq!(
+            found,
+            [
+                "../",
+                "notes.txt",
+                "build/app",
+                "logs/app.log",
+                "build/cache/objects"
+            ]
+        );
+
+        file_explorer.refresh_disk_usage();
+        let names: Vec<_> = wait_sizes(&mut file_explorer)
+            .into_iter()
+            .map(|(name, _)| name.to_owned())
+            .collect();
+        assert_eq!(names, found);
+
+        Ok(())
+    }
+}
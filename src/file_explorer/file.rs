@@ -1,9 +1,25 @@
-use std::{fs::FileType, path::PathBuf};
+use std::{
+    ffi::{OsStr, OsString},
+    fmt::Write,
+    fs::{DirEntry, FileType, Metadata},
+    path::PathBuf,
+};
+
+use super::GitStatus;
 
 /// A file or directory in the file explorer.
+///
+/// Files are only built by the file explorer, and new fields may be added without a breaking change: they can
+/// be read and modified (eg. by a [filter map](crate::FileExplorerBuilder::filter_map)), but not built nor fully
+/// destructured outside of this crate.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub struct File {
-    /// The name of the file or directory.
+    /// The displayed name of the file or directory.
+    ///
+    /// The control characters and the bytes that are not valid UTF-8 of the [`file_name`](File::file_name) are
+    /// escaped (eg. `\n` or `\xff`), and the name is decorated according to the [`NameDecoration`] of the
+    /// file explorer (a trailing `/` for the directories by default).
     ///
     /// # Examples
     /// Suppose you have this tree file, with `passport.png` selected inside `file_explorer`:
@@ -27,6 +43,24 @@ pub struct File {
     /// ```
     pub name: String,
 
+    /// The raw name of the file or directory, as returned by the file system (`..` for the parent directory).
+    ///
+    /// Unlike [`name`](File::name), it is neither decorated nor escaped.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use ratatui_explorer::FileExplorer;
+    ///
+    /// let file_explorer = FileExplorer::new().unwrap();
+    ///
+    /// /* user select `Documents` */
+    ///
+    /// let file = file_explorer.current();
+    /// assert_eq!(file.name, "Documents/");
+    /// assert_eq!(file.file_name, "Documents");
+    /// ```
+    pub file_name: OsString,
+
     /// Returns the path of the file or directory.
     ///
     /// # Examples
@@ -138,9 +172,275 @@ pub struct File {
     /// assert_eq!(file.file_type.unwrap().is_dir(), true);
     /// ```
     pub file_type: Option<FileType>,
+
+    /// The [`GitStatus`] of the file, if the working directory is inside a git repository
+    /// and the file is not clean.
+    ///
+    /// The status of a directory is the most important status of the files it contains
+    /// (an ignored file does not make its directory ignored).
+    ///
+    /// The statuses are read in the background and set by
+    /// [`FileExplorer::poll_git_status`](crate::FileExplorer::poll_git_status), the files keep the statuses last
+    /// read meanwhile.
+    ///
+    /// **Note:** The status is only computed when the `git` feature is enabled, it is always `None` otherwise.
+    pub git_status: Option<GitStatus>,
+
+    /// The [`FileKind`] of the file. Unlike [`file_type`](File::file_type), symbolic links are not followed.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use ratatui_explorer::{FileExplorer, FileKind};
+    ///
+    /// let file_explorer = FileExplorer::new().unwrap();
+    ///
+    /// let file = file_explorer.current();
+    /// if file.kind == FileKind::BrokenSymlink {
+    ///     println!("{} points to nothing", file.name);
+    /// }
+    /// ```
+    pub kind: FileKind,
+
+    /// The target of the file if it is a symbolic link, as stored in the link (it may be relative to the
+    /// directory of the link, and it may not exist).
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use ratatui_explorer::FileExplorer;
+    ///
+    /// let file_explorer = FileExplorer::new().unwrap();
+    ///
+    /// let file = file_explorer.current();
+    /// if let Some(target) = &file.link_target {
+    ///     println!("{} -> {}", file.name, target.display());
+    /// }
+    /// ```
+    pub link_target: Option<PathBuf>,
+}
+
+/// The kind of a [`File`], as shown by `ls`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FileKind {
+    /// A directory.
+    Directory,
+    /// A regular file that is not executable.
+    File,
+    /// A regular file that can be executed.
+    Executable,
+    /// A symbolic link.
+    Symlink,
+    /// A symbolic link whose target does not exist.
+    BrokenSymlink,
+    /// A Unix domain socket.
+    Socket,
+    /// A named pipe.
+    Fifo,
+    /// A block device.
+    BlockDevice,
+    /// A character device.
+    CharDevice,
+    /// Anything else, or a file whose kind could not be read.
+    Other,
+}
+
+/// The decoration appended to the displayed [name](File::name) of the files.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum NameDecoration {
+    /// No decoration.
+    None,
+    /// A `/` after the names of the directories.
+    #[default]
+    TrailingSlash,
+    /// A suffix telling the kind of the file, like `ls -F` does: `/` for the directories, `*` for the executables,
+    /// `@` for the symbolic links, `|` for the named pipes and `=` for the sockets.
+    Classify,
+}
+
+impl NameDecoration {
+    /// Returns the suffix appended to the name of a file of the given `kind`.
+    const fn suffix(self, kind: FileKind, is_dir: bool) -> &'static str {
+        match (self, kind) {
+            (NameDecoration::None, _) => "",
+            (NameDecoration::TrailingSlash, _) if is_dir => "/",
+            (NameDecoration::TrailingSlash, _) => "",
+            (NameDecoration::Classify, FileKind::Directory) => "/",
+            (NameDecoration::Classify, FileKind::Executable) => "*",
+            (NameDecoration::Classify, FileKind::Symlink | FileKind::BrokenSymlink) => "@",
+            (NameDecoration::Classify, FileKind::Fifo) => "|",
+            (NameDecoration::Classify, FileKind::Socket) => "=",
+            (NameDecoration::Classify, _) => "",
+        }
+    }
+}
+
+/// Returns the displayed name of a file: `file_name` with its control characters and invalid bytes escaped,
+/// followed by the suffix of `decoration`.
+pub(super) fn display_name(
+    file_name: &OsStr,
+    kind: FileKind,
+    is_dir: bool,
+    decoration: NameDecoration,
+) -> String {
+    let mut name = String::with_capacity(file_name.len() + 1);
+
+    for chunk in file_name.as_encoded_bytes().utf8_chunks() {
+        for c in chunk.valid().chars() {
+            match c {
+                '\t' => name.push_str("\\t"),
+                '\n' => name.push_str("\\n"),
+                '\r' => name.push_str("\\r"),
+                c if c.is_control() => {
+                    let _ = write!(name, "\\u{{{:x}}}", u32::from(c));
+                }
+                c => name.push(c),
+            }
+        }
+        for byte in chunk.invalid() {
+            let _ = write!(name, "\\x{byte:02x}");
+        }
+    }
+
+    name.push_str(decoration.suffix(kind, is_dir));
+    name
+}
+
+impl FileKind {
+    /// Returns the kind of a file from its own `file_type` and the `metadata` of its target.
+    pub(super) fn new(file_type: Option<FileType>, metadata: Option<&Metadata>) -> Self {
+        if file_type.is_some_and(|file_type| file_type.is_symlink()) {
+            return if metadata.is_some() {
+                FileKind::Symlink
+            } else {
+                FileKind::BrokenSymlink
+            };
+        }
+
+        let Some(metadata) = metadata else {
+            return FileKind::Other;
+        };
+        let file_type = metadata.file_type();
+
+        if file_type.is_dir() {
+            return FileKind::Directory;
+        }
+        if file_type.is_file() {
+            #[cfg(unix)]
+            {
+                use std::os::unix::fs::PermissionsExt;
+                if metadata.permissions().mode() & 0o111 != 0 {
+                    return FileKind::Executable;
+                }
+            }
+            return FileKind::File;
+        }
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::FileTypeExt;
+            if file_type.is_socket() {
+                return FileKind::Socket;
+            }
+            if file_type.is_fifo() {
+                return FileKind::Fifo;
+            }
+            if file_type.is_block_device() {
+                return FileKind::BlockDevice;
+            }
+            if file_type.is_char_device() {
+                return FileKind::CharDevice;
+            }
+        }
+
+        FileKind::Other
+    }
 }
 
 impl File {
+    /// Reads the file of the directory `entry` located at `path`, without its git status.
+    pub(super) fn from_entry(
+        entry: &DirEntry,
+        path: PathBuf,
+        name_decoration: NameDecoration,
+        follow_dir_symlinks: bool,
+    ) -> Self {
+        Self::read(
+            path,
+            entry.file_name(),
+            entry.file_type().ok(),
+            name_decoration,
+            follow_dir_symlinks,
+        )
+    }
+
+    /// Reads the file located at `path`, without its git status. Returns `None` if it does not exist.
+    pub(super) fn from_path(
+        path: PathBuf,
+        name_decoration: NameDecoration,
+        follow_dir_symlinks: bool,
+    ) -> Option<Self> {
+        let link_type = path.symlink_metadata().ok()?.file_type();
+        let file_name = path.file_name().unwrap_or(path.as_os_str()).to_os_string();
+
+        Some(Self::read(
+            path,
+            file_name,
+            Some(link_type),
+            name_decoration,
+            follow_dir_symlinks,
+        ))
+    }
+
+    /// Reads the file located at `path`, whose type without following the symbolic links is `link_type`.
+    fn read(
+        path: PathBuf,
+        file_name: OsString,
+        link_type: Option<FileType>,
+        name_decoration: NameDecoration,
+        follow_dir_symlinks: bool,
+    ) -> Self {
+        let metadata = path.metadata().ok();
+        let file_type = metadata.as_ref().map(|f| f.file_type());
+        let kind = FileKind::new(link_type, metadata.as_ref());
+        let link_target = matches!(kind, FileKind::Symlink | FileKind::BrokenSymlink)
+            .then(|| std::fs::read_link(&path).ok())
+            .flatten();
+        let is_dir =
+            file_type.is_some_and(|f| f.is_dir()) && (follow_dir_symlinks || link_target.is_none());
+
+        let name = display_name(&file_name, kind, is_dir, name_decoration);
+
+        let is_hidden = {
+            #[cfg(unix)]
+            {
+                file_name.as_encoded_bytes().starts_with(b".")
+            }
+
+            #[cfg(windows)]
+            {
+                use std::os::windows::fs::MetadataExt;
+                const FILE_ATTRIBUTE_HIDDEN: u32 = 0x2;
+                metadata.is_some_and(|f| f.file_attributes() & FILE_ATTRIBUTE_HIDDEN != 0)
+            }
+        };
+
+        File {
+            name,
+            file_name,
+            path,
+            is_dir,
+            is_hidden,
+            file_type,
+            git_status: None,
+            kind,
+            link_target,
+        }
+    }
+
+    /// Returns `true` if the file is the entry of the parent directory (`../`).
+    pub(crate) fn is_parent_entry(&self) -> bool {
+        self.file_name == ".."
+    }
+
     /// Returns `true` is the file is a regular file.
     ///
     /// # Examples
@@ -174,6 +474,23 @@ impl File {
         self.file_type.is_some_and(|f| f.is_file())
     }
 
+    /// Returns `true` if the file is a symbolic link, broken or not.
+    ///
+    /// The other fields (like [`is_dir`](File::is_dir) and [`file_type`](File::file_type)) describe the target of the
+    /// link, except [`kind`](File::kind).
+    #[inline]
+    #[must_use]
+    pub const fn is_symlink(&self) -> bool {
+        matches!(self.kind, FileKind::Symlink | FileKind::BrokenSymlink)
+    }
+
+    /// Returns `true` if the file is a symbolic link whose target does not exist.
+    #[inline]
+    #[must_use]
+    pub const fn is_broken_symlink(&self) -> bool {
+        matches!(self.kind, FileKind::BrokenSymlink)
+    }
+
     #[allow(missing_docs)]
     #[inline]
     #[must_use]
@@ -229,3 +546,80 @@ impl File {
         self.file_type
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::fs;
+    use tempfile::TempDir;
+
+    use crate::{FileExplorerBuilder, Result};
+
+    #[test]
+    fn test_display_name_is_escaped() {
+        let name = display_name(
+            OsStr::new("a\tb\nc\u{1b}d"),
+            FileKind::File,
+            false,
+            NameDecoration::TrailingSlash,
+        );
+        assert_eq!(name, r"a\tb\nc\u{1b}d");
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::ffi::OsStrExt;
+
+            let name = display_name(
+                OsStr::from_bytes(b"caf\xe9"),
+                FileKind::Directory,
+                true,
+                NameDecoration::TrailingSlash,
+            );
+            assert_eq!(name, r"caf\xe9/");
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_names_are_decorated() -> Result<()> {
+        use std::os::unix::fs::PermissionsExt;
+
+        let root = TempDir::new()?;
+        fs::create_dir(root.path().join("Documents"))?;
+        fs::write(root.path().join("notes.txt"), "")?;
+        fs::write(root.path().join("run.sh"), "")?;
+        fs::set_permissions(
+            root.path().join("run.sh"),
+            fs::Permissions::from_mode(0o755),
+        )?;
+        std::os::unix::fs::symlink(root.path().join("notes.txt"), root.path().join("latest"))?;
+
+        let mut file_explorer = FileExplorerBuilder::default()
+            .working_dir(root.path())
+            .name_decoration(NameDecoration::Classify)
+            .build()?;
+        let names = |file_explorer: &crate::FileExplorer| {
+            file_explorer
+                .files()
+                .iter()
+                .map(|file| (file.name.clone(), file.file_name.clone()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            names(&file_explorer),
+            [
+                ("../".to_owned(), OsString::from("..")),
+                ("Documents/".to_owned(), OsString::from("Documents")),
+                ("latest@".to_owned(), OsString::from("latest")),
+                ("notes.txt".to_owned(), OsString::from("notes.txt")),
+                ("run.sh*".to_owned(), OsString::from("run.sh")),
+            ]
+        );
+
+        file_explorer.set_name_decoration(NameDecoration::None)?;
+        assert_eq!(file_explorer.files()[1].name, "Documents");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,527 @@
+use std::{
+    fmt,
+    ops::{Bound, Not, RangeBounds},
+    sync::Arc,
+    time::SystemTime,
+};
+
+use super::{File, FileExplorer, FileKind, frecency::fuzzy_match};
+#[cfg(any(feature = "glob", feature = "regex"))]
+use crate::Error;
+use crate::Result;
+
+/// A predicate deciding which files are shown by the [`FileExplorer`].
+///
+/// Filters are registered under a name with [`FileExplorer::add_filter`] (or
+/// [`FileExplorerBuilder::filter`](super::FileExplorerBuilder::filter)) and can be enabled and disabled
+/// independently. A file is shown if it matches every enabled filter.
+///
+/// Most filters only apply to files: directories are always shown so that they can still be browsed.
+/// [`file_type`](Filter::file_type) and the filters made with
+/// [`apply_to_directories`](Filter::apply_to_directories) also decide which directories are shown, and so do
+/// the combinations of such a filter. The parent directory entry (`../`) is always shown.
+///
+/// Filters can be combined with [`and`](Filter::and), [`or`](Filter::or) and `!`.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use ratatui_explorer::{FileExplorer, Filter};
+/// let mut file_explorer = FileExplorer::new().unwrap();
+///
+/// // Only show the big pictures
+/// let pictures = Filter::extension(["png", "jpg"]).and(Filter::size(1 << 20..));
+/// file_explorer.add_filter("pictures", pictures).unwrap();
+///
+/// // Hide the backups
+/// file_explorer.add_filter("no backups", !Filter::extension(["bak"])).unwrap();
+///
+/// // Hide the directories named `target`
+/// let target = Filter::new(|file| file.file_name == "target").apply_to_directories();
+/// file_explorer.add_filter("no target", !target).unwrap();
+/// ```
+#[derive(Clone)]
+pub struct Filter {
+    predicate: Arc<dyn Fn(&File) -> bool + Send + Sync + 'static>,
+    /// The filter also decides which directories are shown.
+    directories: bool,
+}
+
+impl Filter {
+    /// Creates a filter showing the files for which `f` returns `true`. It does not apply to directories
+    /// (see [`apply_to_directories`](Filter::apply_to_directories)).
+    pub fn new(f: impl Fn(&File) -> bool + Send + Sync + 'static) -> Self {
+        Self {
+            predicate: Arc::new(f),
+            directories: false,
+        }
+    }
+
+    /// Creates a filter showing the files with one of the `extensions` (without the leading dot, case insensitive).
+    pub fn extension<I, S>(extensions: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let extensions: Vec<String> = extensions
+            .into_iter()
+            .map(|extension| extension.as_ref().to_lowercase())
+            .collect();
+
+        Self::new(move |file| {
+            file.path
+                .extension()
+                .map(|extension| extension.to_string_lossy().to_lowercase())
+                .is_some_and(|extension| extensions.contains(&extension))
+        })
+    }
+
+    /// Creates a filter showing the files whose name matches the glob `pattern` (eg. `*.{png,jpg}`).
+    ///
+    /// # Errors
+    ///
+    /// Will return [`Error::Filter`] if `pattern` is not a valid glob.
+    #[cfg(feature = "glob")]
+    pub fn glob(pattern: &str) -> Result<Self> {
+        let glob = globset::Glob::new(pattern)
+            .map_err(|err| Error::Filter(err.to_string()))?
+            .compile_matcher();
+
+        Ok(Self::new(move |file| {
+            file.path
+                .file_name()
+                .is_some_and(|name| glob.is_match(name))
+        }))
+    }
+
+    /// Creates a filter showing the files whose name matches the regular expression `pattern`.
+    ///
+    /// # Errors
+    ///
+    /// Will return [`Error::Filter`] if `pattern` is not a valid regular expression.
+    #[cfg(feature = "regex")]
+    pub fn regex(pattern: &str) -> Result<Self> {
+        let regex = regex::Regex::new(pattern).map_err(|err| Error::Filter(err.to_string()))?;
+
+        Ok(Self::new(move |file| {
+            file.path
+                .file_name()
+                .is_some_and(|name| regex.is_match(&name.to_string_lossy()))
+        }))
+    }
+
+    /// Creates a filter showing the files whose name contains the words of `query` in order, the characters
+    /// of each word appearing in order (eg. `rdme` matches `README.md`), ignoring the case.
+    pub fn fuzzy(query: &str) -> Self {
+        let words: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+
+        Self::new(move |file| fuzzy_match(&file.file_name.to_string_lossy().to_lowercase(), &words))
+    }
+
+    /// Creates a filter showing the files whose size in bytes is in `range`.
+    pub fn size(range: impl RangeBounds<u64>) -> Self {
+        let range = owned_bounds(&range);
+
+        Self::new(move |file| {
+            file.path
+                .metadata()
+                .is_ok_and(|metadata| range.contains(&metadata.len()))
+        })
+    }
+
+    /// Creates a filter showing the files last modified in `range`.
+    pub fn modified(range: impl RangeBounds<SystemTime>) -> Self {
+        let range = owned_bounds(&range);
+
+        Self::new(move |file| {
+            file.path
+                .metadata()
+                .and_then(|metadata| metadata.modified())
+                .is_ok_and(|modified| range.contains(&modified))
+        })
+    }
+
+    /// Creates a filter showing the files and directories of the given `kind` (see [`File::kind`]).
+    ///
+    /// Unlike the other filters, it applies to directories: `Filter::file_type(FileKind::Directory)` only shows
+    /// the directories and `!Filter::file_type(FileKind::Directory)` hides them.
+    pub fn file_type(kind: FileKind) -> Self {
+        Self::new(move |file| file.kind == kind).apply_to_directories()
+    }
+
+    /// Returns the filter applied to directories too: the directories it does not match are hidden, and can
+    /// no longer be browsed.
+    #[must_use]
+    pub fn apply_to_directories(mut self) -> Self {
+        self.directories = true;
+        self
+    }
+
+    /// Returns `true` if the filter decides which directories are shown (see
+    /// [`apply_to_directories`](Filter::apply_to_directories)).
+    #[inline]
+    #[must_use]
+    pub const fn applies_to_directories(&self) -> bool {
+        self.directories
+    }
+
+    /// Returns a filter showing the files shown by both `self` and `other`. It applies to directories if one
+    /// of them does.
+    #[must_use]
+    pub fn and(self, other: Filter) -> Self {
+        let directories = self.directories || other.directories;
+        Self {
+            directories,
+            ..Self::new(move |file| self.matches(file) && other.matches(file))
+        }
+    }
+
+    /// Returns a filter showing the files shown by `self` or `other`. It applies to directories if one of them
+    /// does.
+    #[must_use]
+    pub fn or(self, other: Filter) -> Self {
+        let directories = self.directories || other.directories;
+        Self {
+            directories,
+            ..Self::new(move |file| self.matches(file) || other.matches(file))
+        }
+    }
+
+    /// Returns `true` if `file` matches this filter, whether it is a file or a directory.
+    #[inline]
+    #[must_use]
+    pub fn matches(&self, file: &File) -> bool {
+        (self.predicate)(file)
+    }
+
+    /// Returns `true` if `file` is shown by this filter: it matches it, or it is a directory and the filter does
+    /// not apply to directories.
+    #[inline]
+    #[must_use]
+    pub fn shows(&self, file: &File) -> bool {
+        (file.is_dir && !self.directories) || self.matches(file)
+    }
+}
+
+impl Not for Filter {
+    type Output = Filter;
+
+    /// Returns a filter showing the files hidden by `self`. It applies to directories if `self` does.
+    fn not(self) -> Self::Output {
+        let directories = self.directories;
+        Self {
+            directories,
+            ..Self::new(move |file| !self.matches(file))
+        }
+    }
+}
+
+impl fmt::Debug for Filter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Filter").finish_non_exhaustive()
+    }
+}
+
+/// A filter registered in the [`FileExplorer`] under a name.
+#[derive(Debug, Clone)]
+pub(super) struct NamedFilter {
+    pub(super) name: String,
+    filter: Filter,
+    enabled: bool,
+}
+
+impl NamedFilter {
+    pub(super) fn new(name: String, filter: Filter) -> Self {
+        Self {
+            name,
+            filter,
+            enabled: true,
+        }
+    }
+}
+
+impl FileExplorer {
+    /// Adds an enabled filter named `name`, replacing the filter with the same name if any.
+    ///
+    /// The directories stay shown unless the filter [applies to them](Filter::apply_to_directories).
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the current working directory can not be listed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use ratatui_explorer::{FileExplorer, Filter};
+    /// let mut file_explorer = FileExplorer::new().unwrap();
+    /// file_explorer.add_filter("rust", Filter::extension(["rs"])).unwrap();
+    ///
+    /// /* Only Rust files are shown */
+    ///
+    /// file_explorer.toggle_filter("rust").unwrap();
+    ///
+    /// /* All files are shown again */
+    /// ```
+    pub fn add_filter<S: Into<String>>(&mut self, name: S, filter: Filter) -> Result<()> {
+        let named = NamedFilter::new(name.into(), filter);
+
+        self.track(|file_explorer| {
+            match file_explorer
+                .filters
+                .iter_mut()
+                .find(|other| other.name == named.name)
+            {
+                Some(other) => *other = named,
+                None => file_explorer.filters.push(named),
+            }
+            file_explorer.reload().map(|()| None)
+        })?;
+
+        Ok(())
+    }
+
+    /// Removes the filter named `name` and returns it if it exists.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the current working directory can not be listed.
+    pub fn remove_filter(&mut self, name: &str) -> Result<Option<Filter>> {
+        let Some(idx) = self.filters.iter().position(|named| named.name == name) else {
+            return Ok(None);
+        };
+
+        let mut removed = None;
+        self.track(|file_explorer| {
+            removed = Some(file_explorer.filters.remove(idx).filter);
+            file_explorer.reload().map(|()| None)
+        })?;
+
+        Ok(removed)
+    }
+
+    /// Enables or disables the filter named `name`. Does nothing if there is no such filter.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the current working directory can not be listed.
+    pub fn set_filter_enabled(&mut self, name: &str, enabled: bool) -> Result<()> {
+        let Some(idx) = self
+            .filters
+            .iter()
+            .position(|named| named.name == name && named.enabled != enabled)
+        else {
+            return Ok(());
+        };
+
+        self.track(|file_explorer| {
+            file_explorer.filters[idx].enabled = enabled;
+            file_explorer.reload().map(|()| None)
+        })?;
+
+        Ok(())
+    }
+
+    /// Enables the filter named `name` if it is disabled, disables it otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the current working directory can not be listed.
+    pub fn toggle_filter(&mut self, name: &str) -> Result<()> {
+        let enabled = self.is_filter_enabled(name);
+        self.set_filter_enabled(name, !enabled)
+    }
+
+    /// Returns `true` if the filter named `name` exists and is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use ratatui_explorer::{FileExplorerBuilder, Filter, Theme};
+    /// # use ratatui::text::Line;
+    /// let theme = Theme::default().with_title_top(|file_explorer| {
+    ///     if file_explorer.is_filter_enabled("rust") {
+    ///         Line::from(" Rust files ")
+    ///     } else {
+    ///         Line::from(" All files ")
+    ///     }
+    /// });
+    ///
+    /// let file_explorer = FileExplorerBuilder::default()
+    ///     .theme(theme)
+    ///     .filter("rust", Filter::extension(["rs"]))
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    #[must_use]
+    pub fn is_filter_enabled(&self, name: &str) -> bool {
+        self.filters
+            .iter()
+            .any(|named| named.name == name && named.enabled)
+    }
+
+    /// Returns the names of the filters, in the order they were added, and whether they are enabled.
+    pub fn filters(&self) -> impl Iterator<Item = (&str, bool)> {
+        self.filters
+            .iter()
+            .map(|named| (named.name.as_str(), named.enabled))
+    }
+
+    /// Returns `true` if `file` is shown by every enabled filter (see [`Filter::shows`]).
+    pub(super) fn matches_filters(&self, file: &File) -> bool {
+        matches_all(&self.filters, file)
+    }
+}
+
+/// Returns `true` if `file` is shown by every enabled filter of `filters`. The parent directory entry is always
+/// shown.
+pub(super) fn matches_all(filters: &[NamedFilter], file: &File) -> bool {
+    file.is_parent_entry()
+        || filters
+            .iter()
+            .filter(|named| named.enabled)
+            .all(|named| named.filter.shows(file))
+}
+
+/// Clones the bounds of `range`, so that it can be kept.
+fn owned_bounds<T: Clone>(range: &impl RangeBounds<T>) -> (Bound<T>, Bound<T>) {
+    (range.start_bound().cloned(), range.end_bound().cloned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::fs;
+    use tempfile::TempDir;
+
+    use crate::FileExplorerBuilder;
+
+    /// Build this temporary file system:
+    /// ```plaintext
+    /// <unknow>
+    /// └ root
+    ///   ├── Documents
+    ///   ├── big.png      (2 KiB)
+    ///   ├── notes.txt
+    ///   └── small.PNG
+    /// ```
+    fn build_tmp_file_system() -> Result<TempDir> {
+        let root = TempDir::new()?;
+
+        fs::create_dir(root.path().join("Documents"))?;
+        fs::write(root.path().join("big.png"), [0; 2048])?;
+        fs::write(root.path().join("notes.txt"), "notes")?;
+        fs::write(root.path().join("small.PNG"), "png")?;
+
+        Ok(root)
+    }
+
+    fn names(file_explorer: &FileExplorer) -> Vec<&str> {
+        file_explorer
+            .files()
+            .iter()
+            .map(|file| file.name.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_filters_can_be_toggled() -> Result<()> {
+        let root = build_tmp_file_system()?;
+
+        let mut file_explorer = FileExplorerBuilder::default()
+            .working_dir(root.path())
+            .filter("pictures", Filter::extension(["png"]))
+            .filter("big", Filter::size(1024..))
+            .build()?;
+        assert_eq!(names(&file_explorer), ["../", "Documents/", "big.png"]);
+
+        file_explorer.toggle_filter("big")?;
+        assert!(!file_explorer.is_filter_enabled("big"));
+        assert!(file_explorer.is_filter_enabled("pictures"));
+        assert_eq!(
+            names(&file_explorer),
+            ["../", "Documents/", "big.png", "small.PNG"]
+        );
+
+        file_explorer.remove_filter("pictures")?;
+        assert_eq!(
+            file_explorer.filters().collect::<Vec<_>>(),
+            [("big", false)]
+        );
+        assert_eq!(file_explorer.files().len(), 5);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_filters_can_be_combined() -> Result<()> {
+        let root = build_tmp_file_system()?;
+
+        let mut file_explorer = FileExplorerBuilder::build_with_working_dir(root.path())?;
+
+        let filter = !Filter::extension(["png"]).or(Filter::size(..4));
+        file_explorer.add_filter("combined", filter)?;
+        assert_eq!(names(&file_explorer), ["../", "Documents/", "notes.txt"]);
+
+        let filter = Filter::extension(["txt"]).or(Filter::new(|file| file.name.len() > 7));
+        file_explorer.add_filter("combined", filter)?;
+        assert_eq!(
+            names(&file_explorer),
+            ["../", "Documents/", "notes.txt", "small.PNG"]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_filters_applying_to_directories() -> Result<()> {
+        let root = build_tmp_file_system()?;
+
+        let mut file_explorer = FileExplorerBuilder::build_with_working_dir(root.path())?;
+
+        file_explorer.add_filter("dirs", Filter::file_type(FileKind::Directory))?;
+        assert_eq!(names(&file_explorer), ["../", "Documents/"]);
+
+        file_explorer.add_filter("dirs", !Filter::file_type(FileKind::Directory))?;
+        assert_eq!(
+            names(&file_explorer),
+            ["../", "big.png", "notes.txt", "small.PNG"]
+        );
+
+        // A filter applies to directories if one of the filters it combines does.
+        let filter = Filter::fuzzy("doc").apply_to_directories();
+        assert!(filter.applies_to_directories());
+        assert!(!Filter::fuzzy("doc").applies_to_directories());
+        file_explorer.add_filter("dirs", !filter.or(Filter::extension(["txt"])))?;
+        assert_eq!(names(&file_explorer), ["../", "big.png", "small.PNG"]);
+
+        Ok(())
+    }
+
+    #[cfg(all(feature = "glob", feature = "regex"))]
+    #[test]
+    fn test_patterns() -> Result<()> {
+        let root = build_tmp_file_system()?;
+
+        let mut file_explorer = FileExplorerBuilder::build_with_working_dir(root.path())?;
+
+        file_explorer.add_filter("glob", Filter::glob("*.{png,txt}")?)?;
+        assert_eq!(
+            names(&file_explorer),
+            ["../", "Documents/", "big.png", "notes.txt"]
+        );
+
+        file_explorer.add_filter("regex", Filter::regex("^[a-z]+\\.")?)?;
+        assert_eq!(
+            names(&file_explorer),
+            ["../", "Documents/", "big.png", "notes.txt"]
+        );
+        file_explorer.add_filter("regex", Filter::regex("^n")?)?;
+        assert_eq!(names(&file_explorer), ["../", "Documents/", "notes.txt"]);
+
+        assert!(matches!(Filter::glob("[a-"), Err(Error::Filter(_))));
+        assert!(matches!(Filter::regex("("), Err(Error::Filter(_))));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,844 @@
+use std::{
+    collections::VecDeque,
+    ffi::OsString,
+    fs,
+    path::PathBuf,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+    thread,
+};
+
+use super::{
+    File, FileExplorer, FileKind, Filter, FilterMap, NameDecoration, NamedFilter, Outcome, file,
+    filter,
+};
+use crate::{Result, input::Input};
+
+/// A search for the files below a directory, running on background threads.
+#[derive(Debug, Clone)]
+pub(super) struct Find {
+    query: Query,
+    max_depth: Option<usize>,
+    /// Stops the search when the last clone is dropped.
+    handle: Arc<FindHandle>,
+    /// The line numbers of the results already moved to the listing, for a content search.
+    lines: Vec<Option<usize>>,
+}
+
+/// What a [`Find`] looks for.
+#[derive(Debug, Clone)]
+enum Query {
+    /// The files whose name matches the filter.
+    Name(Filter),
+    /// The lines of the regular files matching the regular expression.
+    #[cfg(feature = "regex")]
+    Content(regex::bytes::Regex),
+    /// The given files, absolute or relative to the base directory.
+    Paths(Arc<[PathBuf]>),
+}
+
+/// The state shared between a [`Find`] and its threads.
+#[derive(Debug, Default)]
+struct Shared {
+    /// The results found so far, with their line number for a content search.
+    results: Mutex<Vec<(File, Option<usize>)>>,
+    done: AtomicBool,
+    cancelled: AtomicBool,
+}
+
+impl Shared {
+    fn push(&self, file: File, line: Option<usize>) {
+        if let Ok(mut results) = self.results.lock() {
+            results.push((file, line));
+        }
+    }
+}
+
+#[derive(Debug)]
+struct FindHandle(Arc<Shared>);
+
+impl Drop for FindHandle {
+    fn drop(&mut self) {
+        self.0.cancelled.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Walks the tree below a directory and collects the files matching a query.
+struct Walker {
+    base: PathBuf,
+    query: Query,
+    max_depth: Option<usize>,
+    show_hidden: bool,
+    #[cfg(feature = "gitignore")]
+    respect_ignore: bool,
+    filters: Vec<NamedFilter>,
+    filter_map: Option<Arc<FilterMap>>,
+    name_decoration: NameDecoration,
+    follow_dir_symlinks: bool,
+}
+
+impl Walker {
+    fn run(self, shared: &Arc<Shared>) {
+        #[cfg(feature = "regex")]
+        let scanners = match &self.query {
+            Query::Content(regex) => Some(grep::Scanners::spawn(
+                regex.clone(),
+                self.base.clone(),
+                Arc::clone(shared),
+            )),
+            Query::Name(_) | Query::Paths(_) => None,
+        };
+
+        if let Query::Paths(paths) = &self.query {
+            self.list(paths, shared);
+            return;
+        }
+
+        let mut dirs = VecDeque::from([(self.base.clone(), 0)]);
+
+        while let Some((dir, depth)) = dirs.pop_front() {
+            let Ok(entries) = fs::read_dir(&dir) else {
+                continue;
+            };
+            #[cfg(feature = "gitignore")]
+            let not_ignored = self
+                .respect_ignore
+                .then(|| super::gitignore::not_ignored(&dir));
+
+            let mut entries: Vec<_> = entries.filter_map(|entry| entry.ok()).collect();
+            entries.sort_unstable_by_key(fs::DirEntry::file_name);
+
+            for entry in entries {
+                if shared.cancelled.load(Ordering::Relaxed) {
+                    return;
+                }
+
+                let path = entry.path();
+                #[cfg(feature = "gitignore")]
+                if not_ignored
+                    .as_ref()
+                    .is_some_and(|not_ignored| !not_ignored.contains(&path))
+                {
+                    continue;
+                }
+
+                let file =
+                    File::from_entry(&entry, path, self.name_decoration, self.follow_dir_symlinks);
+                if !self.show_hidden && file.is_hidden {
+                    continue;
+                }
+                // Symbolic links are not followed, so that the walk can not go around in circles.
+                if file.is_dir
+                    && !file.is_symlink()
+                    && self.max_depth.is_none_or(|max_depth| depth + 1 < max_depth)
+                {
+                    dirs.push_back((file.path.clone(), depth + 1));
+                }
+
+                if !filter::matches_all(&self.filters, &file) {
+                    continue;
+                }
+                match &self.query {
+                    Query::Name(pattern) if pattern.matches(&file) => {
+                        if let Some(file) = self.result(file) {
+                            shared.push(file, None);
+                        }
+                    }
+                    Query::Name(_) | Query::Paths(_) => {}
+                    #[cfg(feature = "regex")]
+                    Query::Content(_) => {
+                        if file.is_file()
+                            && let Some(file) = self.result(file)
+                            && let Some(scanners) = &scanners
+                        {
+                            scanners.scan(file);
+                        }
+                    }
+                }
+            }
+        }
+
+        #[cfg(feature = "regex")]
+        if let Some(scanners) = scanners {
+            scanners.join();
+        }
+    }
+
+    /// Reads the existing files among `paths`, in order. Only the filters apply to them.
+    fn list(&self, paths: &[PathBuf], shared: &Shared) {
+        for path in paths {
+            if shared.cancelled.load(Ordering::Relaxed) {
+                return;
+            }
+
+            let Some(file) = File::from_path(
+                self.base.join(path),
+                self.name_decoration,
+                self.follow_dir_symlinks,
+            ) else {
+                continue;
+            };
+            if filter::matches_all(&self.filters, &file)
+                && let Some(file) = self.result(file)
+            {
+                shared.push(file, None);
+            }
+        }
+    }
+
+    /// Names `file` after its path relative to the base directory (or its full path if it is not below it)
+    /// and applies the filter map.
+    fn result(&self, mut file: File) -> Option<File> {
+        let path = file.path.strip_prefix(&self.base).unwrap_or(&file.path);
+        file.name = file::display_name(
+            path.as_os_str(),
+            file.kind,
+            file.is_dir,
+            self.name_decoration,
+        );
+
+        match &self.filter_map {
+            Some(filter_map) => filter_map(file),
+            None => Some(file),
+        }
+    }
+}
+
+#[cfg(feature = "regex")]
+mod grep {
+    use std::{
+        fs,
+        io::{BufRead, BufReader},
+        path::PathBuf,
+        sync::{
+            Arc, Mutex,
+            atomic::Ordering,
+            mpsc::{self, Receiver, Sender},
+        },
+        thread::{self, JoinHandle},
+    };
+
+    use regex::bytes::Regex;
+
+    use super::{File, Shared};
+
+    /// The number of bytes looked at to decide whether a file is binary.
+    const BINARY_PROBE: usize = 8 << 10;
+    /// The maximum number of characters of the matching line shown in a result.
+    const MAX_SNIPPET: usize = 80;
+
+    /// Worker threads scanning the content of files.
+    pub(super) struct Scanners {
+        sender: Sender<File>,
+        workers: Vec<JoinHandle<()>>,
+    }
+
+    impl Scanners {
+        pub(super) fn spawn(regex: Regex, base: PathBuf, shared: Arc<Shared>) -> Self {
+            let count = thread::available_parallelism().map_or(1, |count| count.get().min(8));
+            let (sender, receiver) = mpsc::channel();
+            let receiver = Arc::new(Mutex::new(receiver));
+
+            let workers = (0..count)
+                .map(|_| {
+                    let regex = regex.clone();
+                    let base = base.clone();
+                    let shared = Arc::clone(&shared);
+                    let receiver = Arc::clone(&receiver);
+                    thread::spawn(move || work(&regex, &base, &shared, &receiver))
+                })
+                .collect();
+
+            Self { sender, workers }
+        }
+
+        /// Queues `file` to be scanned.
+        pub(super) fn scan(&self, file: File) {
+            let _ = self.sender.send(file);
+        }
+
+        /// Waits for the queued files to be scanned.
+        pub(super) fn join(self) {
+            drop(self.sender);
+            for worker in self.workers {
+                let _ = worker.join();
+            }
+        }
+    }
+
+    fn work(
+        regex: &Regex,
+        base: &std::path::Path,
+        shared: &Shared,
+        receiver: &Mutex<Receiver<File>>,
+    ) {
+        loop {
+            let Ok(file) = receiver.lock().map(|receiver| receiver.recv()) else {
+                return;
+            };
+            let Ok(file) = file else {
+                return;
+            };
+            if shared.cancelled.load(Ordering::Relaxed) {
+                return;
+            }
+
+            let Some(mut reader) = open_text(&file) else {
+                continue;
+            };
+            let relative = file.path.strip_prefix(base).unwrap_or(&file.path);
+
+            let mut line = Vec::new();
+            let mut line_number = 0;
+            while !shared.cancelled.load(Ordering::Relaxed) {
+                line.clear();
+                if !matches!(reader.read_until(b'\n', &mut line), Ok(1..)) {
+                    break;
+                }
+                line_number += 1;
+
+                let line = line.strip_suffix(b"\n").unwrap_or(&line);
+                if !regex.is_match(line) {
+                    continue;
+                }
+
+                let snippet: String = String::from_utf8_lossy(line)
+                    .trim()
+                    .chars()
+                    .map(|c| if c.is_control() { ' ' } else { c })
+                    .take(MAX_SNIPPET)
+                    .collect();
+                let name = format!("{}:{line_number}: {snippet}", relative.display());
+
+                shared.push(
+                    File {
+                        name,
+                        ..file.clone()
+                    },
+                    Some(line_number),
+                );
+            }
+        }
+    }
+
+    /// Opens `file` to be read line by line, or returns `None` if it can not be read or looks binary.
+    ///
+    /// Only the first bytes are read to decide, so binary files are never loaded.
+    fn open_text(file: &File) -> Option<BufReader<fs::File>> {
+        let mut reader = BufReader::with_capacity(BINARY_PROBE, fs::File::open(&file.path).ok()?);
+        let probe = reader.fill_buf().ok()?;
+
+        (!probe.contains(&0)).then_some(reader)
+    }
+}
+
+impl FileExplorer {
+    /// Searches the files below the working directory whose name matches `pattern`, at most `max_depth` levels
+    /// deep (`Some(1)` only searches the working directory itself).
+    ///
+    /// The search runs on a background thread. The listing is replaced by the results, which are added as
+    /// they are found by [`poll_find`](FileExplorer::poll_find). Hidden files and the
+    /// [filters](FileExplorer::add_filter) are respected, and the symbolic links to directories are not followed.
+    ///
+    /// The results behave like the entries of a directory: [`Input::Right`](crate::Input::Right) enters the
+    /// selected directory or activates the selected file, [`Input::Left`](crate::Input::Left) goes to the parent
+    /// of the selected result with it selected, and a [`DialogMode`](crate::DialogMode) returns the selected
+    /// result when confirming. [`Input::Cancel`](crate::Input::Cancel) and the first entry (`../`) go back to the
+    /// listing of the working directory.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use ratatui_explorer::{FileExplorer, Filter};
+    /// let mut file_explorer = FileExplorer::new().unwrap();
+    /// file_explorer.find(Filter::fuzzy("readme"), Some(4));
+    ///
+    /// loop {
+    ///     file_explorer.poll_find();
+    ///     /* draw the file explorer and handle the events */
+    /// #   break;
+    /// }
+    /// ```
+    #[inline]
+    pub fn find(&mut self, pattern: Filter, max_depth: Option<usize>) {
+        self.start_find(Query::Name(pattern), max_depth);
+    }
+
+    /// Searches the lines matching the regular expression `pattern` in the regular files below the working
+    /// directory, at most `max_depth` levels deep.
+    ///
+    /// Works like [`find`](FileExplorer::find), but the files are scanned by several worker threads and each
+    /// matching line is a result shown as `path:line: snippet`. The binary files are skipped, and so are the
+    /// ignored files if [`respect_ignore`](FileExplorer::set_respect_ignore) is set (with the `gitignore` feature).
+    ///
+    /// Activating a result ([`Input::Right`](crate::Input::Right)) selects its file, like
+    /// [`set_working_file`](FileExplorer::set_working_file) does, and returns [`Outcome::LineActivated`] with
+    /// the line number.
+    ///
+    /// **Note:** This method is only available when the `regex` feature is enabled.
+    ///
+    /// # Errors
+    ///
+    /// Will return [`Error::Filter`](crate::Error::Filter) if `pattern` is not a valid regular expression.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use ratatui_explorer::{FileExplorer, Input, Outcome};
+    /// let mut file_explorer = FileExplorer::new().unwrap();
+    /// file_explorer.grep(r"TODO|FIXME", None).unwrap();
+    ///
+    /// /* the user selects a result */
+    ///
+    /// if let Outcome::LineActivated { file, line } = file_explorer.handle(Input::Right).unwrap() {
+    ///     println!("open {} at line {line}", file.path.display());
+    /// }
+    /// ```
+    #[cfg(feature = "regex")]
+    pub fn grep(&mut self, pattern: &str, max_depth: Option<usize>) -> Result<()> {
+        let regex = regex::bytes::Regex::new(pattern)
+            .map_err(|err| crate::Error::Filter(err.to_string()))?;
+        self.start_find(Query::Content(regex), max_depth);
+
+        Ok(())
+    }
+
+    /// Lists the files at `paths` (eg. the files reported by a compiler) instead of the entries of a directory.
+    ///
+    /// The working directory becomes `base`, and the paths are relative to it unless they are absolute.
+    /// Each entry is shown by its path relative to `base`, in the order of `paths`. The paths that do not exist
+    /// are skipped, and the [filters](FileExplorer::add_filter) apply but the hidden files are shown.
+    ///
+    /// The listing is read right away and behaves like the results of [`find`](FileExplorer::find): it can be
+    /// navigated, filtered, searched and marked, and [`Input::Cancel`](crate::Input::Cancel) or
+    /// [`stop_find`](FileExplorer::stop_find) go back to the listing of `base`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if `base` can not be made the working directory (see [`set_cwd`](FileExplorer::set_cwd)).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use ratatui_explorer::FileExplorer;
+    /// let mut file_explorer = FileExplorer::new().unwrap();
+    /// file_explorer
+    ///     .show_paths("/project", ["src/lib.rs", "src/widget.rs", "/etc/hosts"])
+    ///     .unwrap();
+    /// ```
+    pub fn show_paths<P, I>(&mut self, base: P, paths: I) -> Result<()>
+    where
+        P: Into<PathBuf>,
+        I: IntoIterator,
+        I::Item: Into<PathBuf>,
+    {
+        let base = base.into();
+        let paths = paths.into_iter().map(Into::into).collect();
+
+        self.track(|file_explorer| {
+            if base != file_explorer.cwd {
+                file_explorer.load_cwd(base)?;
+            }
+            file_explorer.start_find(Query::Paths(paths), None);
+
+            Ok(None)
+        })?;
+
+        Ok(())
+    }
+
+    fn start_find(&mut self, query: Query, max_depth: Option<usize>) {
+        let shared = Arc::new(Shared::default());
+        let walker = Walker {
+            base: self.cwd.clone(),
+            query: query.clone(),
+            max_depth,
+            show_hidden: self.show_hidden,
+            #[cfg(feature = "gitignore")]
+            respect_ignore: self.respect_ignore,
+            filters: self.filters.clone(),
+            filter_map: self.filter.clone(),
+            name_decoration: self.name_decoration,
+            follow_dir_symlinks: self.follow_dir_symlinks,
+        };
+
+        let thread_shared = Arc::clone(&shared);
+        let run = move || {
+            walker.run(&thread_shared);
+            thread_shared.done.store(true, Ordering::Relaxed);
+        };
+        // A known list of paths is read right away, only the walks run in the background.
+        if matches!(query, Query::Paths(_)) {
+            run();
+        } else {
+            thread::spawn(run);
+        }
+
+        let file_name = OsString::from("..");
+        self.files = vec![File {
+            name: file::display_name(&file_name, FileKind::Directory, true, self.name_decoration),
+            file_name,
+            path: self.cwd.clone(),
+            is_dir: true,
+            is_hidden: false,
+            file_type: None,
+            git_status: None,
+            kind: FileKind::Directory,
+            link_target: None,
+        }];
+        self.selected = 0;
+        self.search = None;
+        self.find = Some(Find {
+            query,
+            max_depth,
+            handle: Arc::new(FindHandle(shared)),
+            lines: Vec::new(),
+        });
+        self.poll_find();
+    }
+
+    /// Adds the results found since the last call to the listing. Returns `true` if there are new results.
+    ///
+    /// Call it regularly (eg. before drawing) while [`is_finding`](FileExplorer::is_finding) returns `true`.
+    /// It is also called by [`handle`](FileExplorer::handle).
+    pub fn poll_find(&mut self) -> bool {
+        let Some(find) = self.find.as_mut() else {
+            return false;
+        };
+        let Ok(results) = find.handle.0.results.lock() else {
+            return false;
+        };
+
+        let new_results = &results[find.lines.len()..];
+        for (file, line) in new_results {
+            self.files.push(file.clone());
+            find.lines.push(*line);
+        }
+
+        !new_results.is_empty()
+    }
+
+    /// Stops the search and lists the working directory again.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the working directory can not be listed.
+    #[inline]
+    pub fn stop_find(&mut self) -> Result<()> {
+        self.track(|file_explorer| file_explorer.leave_find().map(|()| None))?;
+
+        Ok(())
+    }
+
+    /// Returns `true` if the listing shows the results of a [search](FileExplorer::find) or
+    /// [given paths](FileExplorer::show_paths).
+    #[inline]
+    #[must_use]
+    pub const fn is_finding(&self) -> bool {
+        self.find.is_some()
+    }
+
+    /// Returns `true` if the search is still walking the tree.
+    #[inline]
+    #[must_use]
+    pub fn is_find_running(&self) -> bool {
+        self.find
+            .as_ref()
+            .is_some_and(|find| !find.handle.0.done.load(Ordering::Relaxed))
+    }
+
+    /// Returns the line number of the selected result of a [content search](FileExplorer::grep), if any.
+    #[must_use]
+    pub fn current_line(&self) -> Option<usize> {
+        let find = self.find.as_ref()?;
+        let idx = self.selected.checked_sub(1)?;
+
+        find.lines.get(idx).copied().flatten()
+    }
+
+    /// Handles the inputs that have a find mode specific meaning.
+    ///
+    /// Returns `None` if the input should be handled as usual.
+    pub(super) fn handle_find(&mut self, input: Input) -> Result<Option<Outcome>> {
+        if self.find.is_none() || input != Input::Cancel {
+            return Ok(None);
+        }
+
+        self.leave_find()?;
+        Ok(Some(Outcome::Nothing))
+    }
+
+    /// Selects the file of the selected result of a content search and returns its line, without notifying the hooks.
+    ///
+    /// Returns `None` if the selected entry is not the result of a content search.
+    pub(super) fn activate_line(&mut self) -> Result<Option<Outcome>> {
+        let (Some(line), Some(current)) = (self.current_line(), self.try_current()) else {
+            return Ok(None);
+        };
+
+        let path = current.path.clone();
+        self.load_working_file(path)?;
+        Ok(self.try_current().map(|file| Outcome::LineActivated {
+            file: file.clone(),
+            line,
+        }))
+    }
+
+    /// Goes to the parent of the selected result with it selected, or back to the working directory if the
+    /// first entry is selected, without notifying the hooks.
+    pub(super) fn leave_find_to_selected(&mut self) -> Result<()> {
+        match self.try_current() {
+            Some(current) if self.selected != 0 => {
+                let path = current.path.clone();
+                self.load_working_file(path)
+            }
+            _ => self.leave_find(),
+        }
+    }
+
+    /// Stops the search and lists the working directory again, without notifying the hooks.
+    fn leave_find(&mut self) -> Result<()> {
+        self.find = None;
+        self.reload()
+    }
+
+    /// Starts the search again, eg. after the filters changed. Returns `false` if there is no search.
+    pub(super) fn restart_find(&mut self) -> bool {
+        let Some(find) = self.find.take() else {
+            return false;
+        };
+
+        self.start_find(find.query, find.max_depth);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, time::Duration};
+    use tempfile::TempDir;
+
+    use crate::{DialogMode, FileExplorer, FileExplorerBuilder, Filter, Input, Outcome, Result};
+
+    /// Build this temporary file system:
+    /// ```plaintext
+    /// <unknow>
+    /// └ root
+    ///   ├── .git
+    ///   │   └── readme
+    ///   ├── Documents
+    ///   │   └── resume.pdf
+    ///   ├── notes
+    ///   │   └── deep
+    ///   │       └── readme.md
+    ///   └── README.md
+    /// ```
+    fn build_tmp_file_system() -> Result<TempDir> {
+        let root = TempDir::new()?;
+
+        fs::create_dir_all(root.path().join(".git"))?;
+        fs::create_dir_all(root.path().join("Documents"))?;
+        fs::create_dir_all(root.path().join("notes/deep"))?;
+        fs::write(root.path().join(".git/readme"), "")?;
+        fs::write(root.path().join("Documents/resume.pdf"), "")?;
+        fs::write(root.path().join("notes/deep/readme.md"), "")?;
+        fs::write(root.path().join("README.md"), "")?;
+
+        Ok(root)
+    }
+
+    /// Waits for the search to finish and returns the names of the results.
+    fn wait_results(file_explorer: &mut FileExplorer) -> Vec<String> {
+        while file_explorer.is_find_running() {
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        file_explorer.poll_find();
+
+        file_explorer
+            .files()
+            .iter()
+            .map(|file| file.name.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_find_streams_results() -> Result<()> {
+        let root = build_tmp_file_system()?;
+
+        let mut file_explorer = FileExplorerBuilder::build_with_working_dir(root.path())?;
+        file_explorer.find(Filter::fuzzy("rdme"), None);
+        assert!(file_explorer.is_finding());
+        assert_eq!(
+            wait_results(&mut file_explorer),
+            ["../", "README.md", "notes/deep/readme.md"]
+        );
+
+        file_explorer.find(Filter::fuzzy("rdme"), Some(2));
+        assert_eq!(wait_results(&mut file_explorer), ["../", "README.md"]);
+
+        file_explorer.set_show_hidden(true)?;
+        assert_eq!(
+            wait_results(&mut file_explorer),
+            ["../", "README.md", ".git/readme"]
+        );
+
+        file_explorer.handle(Input::Cancel)?;
+        assert!(!file_explorer.is_finding());
+        assert_eq!(file_explorer.cwd(), root.path());
+        assert_eq!(file_explorer.files().len(), 5);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_find_results_behave_like_entries() -> Result<()> {
+        let root = build_tmp_file_system()?;
+
+        let mut file_explorer = FileExplorerBuilder::default()
+            .working_dir(root.path())
+            .dialog_mode(DialogMode::OpenFile)
+            .build()?;
+
+        file_explorer.handle(Input::Find)?;
+        for c in "readme".chars() {
+            file_explorer.handle(Input::Char(c))?;
+        }
+        file_explorer.handle(Input::Confirm)?;
+        wait_results(&mut file_explorer);
+
+        file_explorer.handle(Input::End)?;
+        let outcome = file_explorer.handle(Input::Confirm)?;
+        assert_eq!(
+            outcome,
+            Outcome::Confirmed(vec![root.path().join("notes/deep/readme.md")])
+        );
+
+        let outcome = file_explorer.handle(Input::Left)?;
+        assert!(matches!(outcome, Outcome::DirectoryChanged { .. }));
+        assert!(!file_explorer.is_finding());
+        assert_eq!(file_explorer.cwd(), &root.path().join("notes/deep"));
+        assert_eq!(file_explorer.current().name, "readme.md");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_paths_are_listed_relative_to_base() -> Result<()> {
+        let root = build_tmp_file_system()?;
+        let outside = TempDir::new()?;
+        fs::write(outside.path().join("error.log"), "")?;
+
+        let mut file_explorer = FileExplorerBuilder::build_with_paths(
+            root.path(),
+            [
+                "notes/deep/readme.md".into(),
+                root.path().join("README.md"),
+                "missing.rs".into(),
+                ".git/readme".into(),
+                "notes/deep".into(),
+                outside.path().join("error.log"),
+            ],
+        )?;
+        assert_eq!(file_explorer.cwd(), root.path());
+        assert!(!file_explorer.is_find_running());
+        let names: Vec<_> = file_explorer
+            .files()
+            .iter()
+            .map(|file| file.name.clone())
+            .collect();
+        assert_eq!(
+            names[..5],
+            [
+                "../",
+                "notes/deep/readme.md",
+                "README.md",
+                ".git/readme",
+                "notes/deep/"
+            ]
+        );
+        assert_eq!(
+            names[5],
+            outside.path().join("error.log").display().to_string()
+        );
+
+        file_explorer.add_filter("no logs", Filter::new(|file| file.file_name != "error.log"))?;
+        assert_eq!(file_explorer.files().len(), 5);
+
+        file_explorer.handle(Input::ToggleMark)?;
+        file_explorer.handle(Input::ToggleMark)?;
+        assert_eq!(
+            file_explorer.marked().collect::<Vec<_>>(),
+            [root.path().join("notes/deep/readme.md")]
+        );
+
+        let outcome = file_explorer.handle(Input::Right)?;
+        assert!(
+            matches!(outcome, Outcome::FileActivated(file) if file.path == root.path().join("README.md"))
+        );
+
+        file_explorer.handle(Input::Cancel)?;
+        assert!(!file_explorer.is_finding());
+        assert_eq!(file_explorer.files().len(), 4);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_grep_activates_lines() -> Result<()> {
+        let root = build_tmp_file_system()?;
+        fs::write(root.path().join("README.md"), "# Title\n\nTODO: write it\n")?;
+        fs::write(
+            root.path().join("notes/deep/readme.md"),
+            "  todo   \nTODO again",
+        )?;
+        fs::write(root.path().join("Documents/resume.pdf"), b"TODO\0binary")?;
+        // Lines longer than the read buffer are still read whole.
+        fs::write(
+            root.path().join("Documents/server.log"),
+            format!("{}\nTODO later\n", "x".repeat(20_000)),
+        )?;
+
+        let mut file_explorer = FileExplorerBuilder::build_with_working_dir(root.path())?;
+        assert!(file_explorer.grep("(", None).is_err());
+        file_explorer.grep("(?i)todo", None)?;
+
+        let mut results = wait_results(&mut file_explorer);
+        results.sort();
+        assert_eq!(
+            results,
+            [
+                "../",
+                "Documents/server.log:2: TODO later",
+                "README.md:3: TODO: write it",
+                "notes/deep/readme.md:1: todo",
+                "notes/deep/readme.md:2: TODO again",
+            ]
+        );
+
+        let idx = file_explorer
+            .files()
+            .iter()
+            .position(|file| file.name == "notes/deep/readme.md:2: TODO again")
+            .unwrap();
+        file_explorer.set_selected_idx(idx);
+        assert_eq!(file_explorer.current_line(), Some(2));
+
+        let outcome = file_explorer.handle(Input::Right)?;
+        assert_eq!(
+            outcome,
+            Outcome::LineActivated {
+                file: file_explorer.current().clone(),
+                line: 2
+            }
+        );
+        assert!(!file_explorer.is_finding());
+        assert_eq!(file_explorer.cwd(), &root.path().join("notes/deep"));
+        assert_eq!(file_explorer.current().name, "readme.md");
+        assert_eq!(file_explorer.current_line(), None);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,220 @@
+use std::{collections::VecDeque, path::Path};
+
+use super::{File, FileExplorer, file};
+use crate::Result;
+
+/// The default maximum depth of the flattened listing.
+pub(super) const DEFAULT_FLATTEN_DEPTH: usize = 4;
+/// The number of entries read after which no more directories are read, to keep the listing responsive.
+const MAX_FLATTEN_ENTRIES: usize = 10_000;
+
+impl FileExplorer {
+    /// Sets whether the listing is flattened: instead of the entries of the working directory, it shows all the
+    /// files below it, at most [`flatten_depth`](FileExplorer::flatten_depth) levels deep.
+    ///
+    /// The files are shown by their path relative to the working directory, sorted like the entries of a
+    /// directory. The directories themselves are not listed, and the symbolic links to directories are not
+    /// followed. The hidden and ignored entries are skipped like in a regular listing, and the
+    /// [filters](FileExplorer::add_filter) and the [search](FileExplorer::search) apply to the files.
+    ///
+    /// The tree is read level by level while the working directory is listed, and no more directories are read
+    /// once 10 000 entries were, so the deepest files of big trees may be missing. Use
+    /// [`find`](FileExplorer::find) to search them in the background instead.
+    ///
+    /// The listing stays flattened when the working directory changes.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the current working directory can not be listed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use ratatui_explorer::FileExplorer;
+    /// let mut file_explorer = FileExplorer::new().unwrap();
+    /// file_explorer.set_flatten_depth(3).unwrap();
+    /// file_explorer.set_flatten(true).unwrap();
+    ///
+    /// /* `src/widget/icons.rs` is listed */
+    /// ```
+    #[inline]
+    pub fn set_flatten(&mut self, flatten: bool) -> Result<()> {
+        self.track(|file_explorer| {
+            file_explorer.flatten = flatten;
+            file_explorer.reload().map(|()| None)
+        })?;
+
+        Ok(())
+    }
+
+    /// Returns whether the listing is flattened (see [`set_flatten`](FileExplorer::set_flatten)).
+    #[inline]
+    #[must_use]
+    pub const fn flatten(&self) -> bool {
+        self.flatten
+    }
+
+    /// Sets the maximum depth of the flattened listing. `1` only lists the files of the working directory.
+    /// Defaults to `4`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the listing is flattened and the current working directory can not be listed.
+    #[inline]
+    pub fn set_flatten_depth(&mut self, depth: usize) -> Result<()> {
+        self.track(|file_explorer| {
+            file_explorer.flatten_depth = depth;
+            if file_explorer.flatten {
+                file_explorer.reload()?;
+            }
+
+            Ok(None)
+        })?;
+
+        Ok(())
+    }
+
+    /// Returns the maximum depth of the flattened listing (see [`set_flatten_depth`](FileExplorer::set_flatten_depth)).
+    #[inline]
+    #[must_use]
+    pub const fn flatten_depth(&self) -> usize {
+        self.flatten_depth
+    }
+
+    /// Replaces the directories of `entries`, the entries of `dir`, by the files below them, named after
+    /// their path relative to `dir`. The shallowest directories are read first.
+    pub(super) fn flatten_entries(&self, dir: &Path, entries: Vec<File>) -> Vec<File> {
+        let mut files = Vec::new();
+        let mut read = entries.len();
+        let mut levels = VecDeque::from([(entries, 1)]);
+
+        while let Some((entries, depth)) = levels.pop_front() {
+            for mut entry in entries {
+                if !entry.is_dir {
+                    if let Ok(relative) = entry.path.strip_prefix(dir) {
+                        entry.name = file::display_name(
+                            relative.as_os_str(),
+                            entry.kind,
+                            entry.is_dir,
+                            self.name_decoration,
+                        );
+                    }
+                    files.push(entry);
+                } else if depth < self.flatten_depth
+                    && !entry.is_symlink()
+                    && read < MAX_FLATTEN_ENTRIES
+                    && let Ok(entries) = self.read_entries(&entry.path)
+                {
+                    read += entries.len();
+                    levels.push_back((entries, depth + 1));
+                }
+            }
+        }
+
+        files
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use tempfile::TempDir;
+
+    use super::MAX_FLATTEN_ENTRIES;
+    use crate::{FileExplorerBuilder, Filter, Input, Result};
+
+    /// Build this temporary file system:
+    /// ```plaintext
+    /// <unknow>
+    /// └ root
+    ///   ├── .cache
+    ///   │   └── old.log
+    ///   ├── 2024
+    ///   │   ├── 12
+    ///   │   │   └── app.log
+    ///   │   └── summary.txt
+    ///   ├── 2025
+    ///   │   └── app.log
+    ///   └── app.log
+    /// ```
+    fn build_tmp_file_system() -> Result<TempDir> {
+        let root = TempDir::new()?;
+
+        fs::create_dir_all(root.path().join(".cache"))?;
+        fs::create_dir_all(root.path().join("2024/12"))?;
+        fs::create_dir_all(root.path().join("2025"))?;
+        fs::write(root.path().join(".cache/old.log"), "")?;
+        fs::write(root.path().join("2024/12/app.log"), "")?;
+        fs::write(root.path().join("2024/summary.txt"), "")?;
+        fs::write(root.path().join("2025/app.log"), "")?;
+        fs::write(root.path().join("app.log"), "")?;
+
+        Ok(root)
+    }
+
+    fn names(file_explorer: &crate::FileExplorer) -> Vec<&str> {
+        file_explorer
+            .files()
+            .iter()
+            .map(|file| file.name.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_flatten_lists_files_below() -> Result<()> {
+        let root = build_tmp_file_system()?;
+
+        let mut file_explorer = FileExplorerBuilder::build_with_working_dir(root.path())?;
+        file_explorer.handle(Input::ToggleFlatten)?;
+        assert!(file_explorer.flatten());
+        assert_eq!(
+            names(&file_explorer),
+            [
+                "../",
+                "2024/12/app.log",
+                "2024/summary.txt",
+                "2025/app.log",
+                "app.log"
+            ]
+        );
+
+        file_explorer.set_flatten_depth(2)?;
+        assert_eq!(
+            names(&file_explorer),
+            ["../", "2024/summary.txt", "2025/app.log", "app.log"]
+        );
+
+        file_explorer.add_filter("logs", Filter::extension(["log"]))?;
+        file_explorer.set_show_hidden(true)?;
+        assert_eq!(
+            names(&file_explorer),
+            ["../", ".cache/old.log", "2025/app.log", "app.log"]
+        );
+
+        file_explorer.set_cwd(root.path().join("2024"))?;
+        assert_eq!(names(&file_explorer), ["../", "12/app.log"]);
+
+        file_explorer.handle(Input::ToggleFlatten)?;
+        assert_eq!(names(&file_explorer), ["../", "12/"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_flatten_stops_reading_big_trees() -> Result<()> {
+        let root = TempDir::new()?;
+        for idx in 0..MAX_FLATTEN_ENTRIES {
+            fs::write(root.path().join(idx.to_string()), "")?;
+        }
+        fs::create_dir(root.path().join("logs"))?;
+        fs::write(root.path().join("logs/app.log"), "")?;
+
+        let mut file_explorer = FileExplorerBuilder::build_with_working_dir(root.path())?;
+        file_explorer.set_flatten(true)?;
+        let listed = names(&file_explorer);
+        assert_eq!(listed.len(), 1 + MAX_FLATTEN_ENTRIES);
+        assert!(!listed.contains(&"logs/app.log"));
+
+        Ok(())
+    }
+}
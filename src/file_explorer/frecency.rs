@@ -0,0 +1,775 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    io::{Error, ErrorKind, Result},
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use super::{FileExplorer, data_home, write_atomically};
+
+/// Total rank above which the ranks of all directories are aged.
+const MAX_TOTAL_RANK: f64 = 10_000.0;
+
+/// A database of visited directories ranked by frequency and recency, like
+/// [zoxide](https://github.com/ajeetdsouza/zoxide) or [z](https://github.com/rupa/z).
+///
+/// Each directory has a rank, incremented on every visit, and the time of its last visit.
+/// Queries are fuzzy matched against the visited directories and sorted by their score: the rank
+/// multiplied by 4 if the directory was visited in the last hour, by 2 in the last day, by 1/2 in the
+/// last week and by 1/4 otherwise. When the total rank gets too high, all ranks are aged and
+/// rarely visited directories are forgotten.
+///
+/// The visits are saved in batches: on [`flush`](Frecency::flush) and when the database is dropped.
+/// Several databases can be opened from the same file (eg. by the tabs of an
+/// [`ExplorerTabs`](crate::ExplorerTabs)): saving reads the file again and adds the new visits to it, so none of
+/// them overwrites the visits of the others.
+///
+/// # File format
+///
+/// The database file uses the format of z: one directory per line, with its path, its rank and the
+/// UNIX timestamp of its last visit, separated by `|`.
+///
+/// ```plaintext
+/// /home/me/dev/ratatui-explorer|42.5|1760000000
+/// /home/me/Documents|3|1759000000
+/// ```
+///
+/// # Examples
+///
+/// ```no_run
+/// # use ratatui_explorer::{FileExplorerBuilder, Frecency};
+/// let mut frecency = Frecency::open_default().unwrap();
+/// frecency.import_existing().unwrap();
+///
+/// let file_explorer = FileExplorerBuilder::default()
+///     .frecency(frecency)
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Default, PartialEq)]
+pub struct Frecency {
+    path: Option<PathBuf>,
+    entries: BTreeMap<PathBuf, Entry>,
+    /// The visits recorded since the database was last saved, counted by the rank.
+    visits: BTreeMap<PathBuf, Entry>,
+    /// The directories imported since the database was last saved.
+    imported: BTreeMap<PathBuf, Entry>,
+    /// The directories forgotten since the database was last saved.
+    forgotten: BTreeSet<PathBuf>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Entry {
+    rank: f64,
+    last_visit: u64,
+}
+
+impl Entry {
+    fn score(self, now: u64) -> f64 {
+        const HOUR: u64 = 60 * 60;
+        const DAY: u64 = 24 * HOUR;
+        const WEEK: u64 = 7 * DAY;
+
+        let elapsed = now.saturating_sub(self.last_visit);
+        let factor = if elapsed < HOUR {
+            4.0
+        } else if elapsed < DAY {
+            2.0
+        } else if elapsed < WEEK {
+            0.5
+        } else {
+            0.25
+        };
+
+        self.rank * factor
+    }
+}
+
+impl Frecency {
+    /// Creates an empty database that is not persisted.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads the database from the file at `path`, forgetting the directories that no longer exist.
+    /// The visits are then saved to this file.
+    ///
+    /// The file does not have to exist, it is created on the first visit.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the file exists but can not be read.
+    pub fn open<P: Into<PathBuf>>(path: P) -> Result<Self> {
+        let path = path.into();
+
+        let mut frecency = Self::new();
+        match fs::read_to_string(&path) {
+            Ok(content) => frecency.merge_z(&content),
+            Err(err) if err.kind() == ErrorKind::NotFound => {}
+            Err(err) => return Err(err),
+        }
+        frecency.prune();
+        frecency.path = Some(path);
+
+        Ok(frecency)
+    }
+
+    /// Loads the database from the file at [`default_path`](Frecency::default_path).
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if no home directory can be found or if the file can not be read.
+    pub fn open_default() -> Result<Self> {
+        let path = Self::default_path()
+            .ok_or_else(|| Error::new(ErrorKind::NotFound, "no data directory found"))?;
+        Self::open(path)
+    }
+
+    /// Returns the default database file: `$XDG_DATA_HOME/ratatui-explorer/frecency`,
+    /// or `~/.local/share/ratatui-explorer/frecency` if `$XDG_DATA_HOME` is not set.
+    #[must_use]
+    pub fn default_path() -> Option<PathBuf> {
+        data_home().map(|data_home| data_home.join("ratatui-explorer").join("frecency"))
+    }
+
+    /// Returns the file the database is saved to, if any.
+    #[inline]
+    #[must_use]
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// Returns the number of directories in the database.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if no directory has been visited.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Records a visit of `path`. It is saved on the next [`flush`](Frecency::flush).
+    ///
+    /// The paths that are not valid UTF-8 or contain a line break can not be written to the database file,
+    /// their visits are not recorded.
+    pub fn record<P: Into<PathBuf>>(&mut self, path: P) {
+        let path = path.into();
+        if encode_path(&path).is_none() {
+            return;
+        }
+        let now = now();
+        for entries in [&mut self.entries, &mut self.visits] {
+            let entry = entries.entry(path.clone()).or_insert(Entry {
+                rank: 0.0,
+                last_visit: now,
+            });
+            entry.rank += 1.0;
+            entry.last_visit = now;
+        }
+        self.forgotten.remove(&path);
+
+        self.age();
+    }
+
+    /// Forgets `path`. Returns `true` if it was in the database. It is saved on the next
+    /// [`flush`](Frecency::flush).
+    pub fn remove(&mut self, path: &Path) -> bool {
+        let removed = self.entries.remove(path).is_some();
+        if removed {
+            self.visits.remove(path);
+            self.forgotten.insert(path.to_path_buf());
+        }
+
+        removed
+    }
+
+    /// Saves the database if it changed since it was last saved.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the database can not be saved.
+    pub fn flush(&mut self) -> Result<()> {
+        if self.is_dirty() {
+            self.save()?;
+        }
+
+        Ok(())
+    }
+
+    /// Forgets the directories that no longer exist. They are removed from the file on the next
+    /// [`flush`](Frecency::flush).
+    pub fn prune(&mut self) {
+        let forgotten = &mut self.forgotten;
+        self.entries.retain(|path, _| {
+            let exists = path.is_dir();
+            if !exists {
+                forgotten.insert(path.clone());
+            }
+            exists
+        });
+    }
+
+    /// Returns the visited directories fuzzy matching `query`, the best first.
+    ///
+    /// A directory matches if all the whitespace separated words of `query` are found, in order and
+    /// ignoring case, in its path, the characters of each word appearing in order. An empty query matches
+    /// every directory.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use ratatui_explorer::Frecency;
+    /// let frecency = Frecency::open_default().unwrap();
+    ///
+    /// if let Some(best) = frecency.query("rat exp").first() {
+    ///     println!("Jumping to {}", best.display());
+    /// }
+    /// ```
+    #[must_use]
+    pub fn query(&self, query: &str) -> Vec<&Path> {
+        let now = now();
+        let words: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+
+        let mut matches: Vec<(&Path, f64)> = self
+            .entries
+            .iter()
+            .filter(|(path, _)| fuzzy_match(&path.to_string_lossy().to_lowercase(), &words))
+            .map(|(path, entry)| (path.as_path(), entry.score(now)))
+            .collect();
+        matches.sort_by(|(_, s1), (_, s2)| s2.total_cmp(s1));
+
+        matches.into_iter().map(|(path, _)| path).collect()
+    }
+
+    /// Imports the databases of zoxide and z if they exist, then saves the database.
+    ///
+    /// The zoxide database is looked for in `$_ZO_DATA_DIR/db.zo` or `$XDG_DATA_HOME/zoxide/db.zo`,
+    /// the z database in `$_Z_DATA` or `~/.z`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if a database exists but can not be read, or if the database can not be saved.
+    pub fn import_existing(&mut self) -> Result<()> {
+        let zoxide = std::env::var_os("_ZO_DATA_DIR")
+            .map(PathBuf::from)
+            .or_else(|| data_home().map(|data_home| data_home.join("zoxide")))
+            .map(|dir| dir.join("db.zo"));
+        let z = std::env::var_os("_Z_DATA")
+            .map(PathBuf::from)
+            .or_else(|| std::env::home_dir().map(|home| home.join(".z")));
+
+        let mut imported = Self::new();
+        if let Some(zoxide) = zoxide.filter(|path| path.is_file()) {
+            imported.merge_zoxide(&fs::read(zoxide)?)?;
+        }
+        if let Some(z) = z.filter(|path| path.is_file()) {
+            imported.merge_z(&fs::read_to_string(z)?);
+        }
+
+        self.import(imported)
+    }
+
+    /// Imports a z data file (`~/.z` by default), then saves the database.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the file can not be read, or if the database can not be saved.
+    pub fn import_z<P: AsRef<Path>>(&mut self, path: P) -> Result<()> {
+        let mut imported = Self::new();
+        imported.merge_z(&fs::read_to_string(path)?);
+        self.import(imported)
+    }
+
+    /// Imports a zoxide database (`db.zo`), then saves the database.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the file can not be read or is not a zoxide database, or if the database
+    /// can not be saved.
+    pub fn import_zoxide<P: AsRef<Path>>(&mut self, path: P) -> Result<()> {
+        let mut imported = Self::new();
+        imported.merge_zoxide(&fs::read(path)?)?;
+        self.import(imported)
+    }
+
+    /// Saves the database to its file. Does nothing if the database is not persisted.
+    ///
+    /// The file is read again first, so the changes saved by other databases since this one was loaded are kept
+    /// and learned: the visits recorded since the last save are added to the saved ranks, and the imported and
+    /// forgotten directories are merged into and removed from the saved ones.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the file can not be read, or if it or its parent directories can not be written.
+    pub fn save(&mut self) -> Result<()> {
+        let Some(path) = &self.path else {
+            self.visits.clear();
+            self.imported.clear();
+            self.forgotten.clear();
+            return Ok(());
+        };
+
+        let mut saved = Self::new();
+        match fs::read_to_string(path) {
+            Ok(content) => saved.merge_z(&content),
+            Err(err) if err.kind() == ErrorKind::NotFound => {}
+            Err(err) => return Err(err),
+        }
+        for (dir, entry) in &self.imported {
+            saved.merge(dir.clone(), *entry);
+        }
+        for (dir, visit) in &self.visits {
+            let entry = saved.entries.entry(dir.clone()).or_insert(Entry {
+                rank: 0.0,
+                last_visit: visit.last_visit,
+            });
+            entry.rank += visit.rank;
+            entry.last_visit = entry.last_visit.max(visit.last_visit);
+        }
+        for dir in &self.forgotten {
+            saved.entries.remove(dir);
+        }
+        saved.age();
+
+        let content: String = saved
+            .entries
+            .iter()
+            .filter_map(|(path, entry)| {
+                let path = encode_path(path)?;
+                Some(format!("{path}|{}|{}\n", entry.rank, entry.last_visit))
+            })
+            .collect();
+        write_atomically(path, &content)?;
+
+        // The pending changes are only dropped once saved, so a failed save is retried by the next one.
+        self.entries = std::mem::take(&mut saved.entries);
+        self.visits.clear();
+        self.imported.clear();
+        self.forgotten.clear();
+
+        Ok(())
+    }
+
+    /// Returns `true` if visits were recorded or directories imported or forgotten since the database was last
+    /// saved.
+    #[inline]
+    #[must_use]
+    pub fn is_dirty(&self) -> bool {
+        !self.visits.is_empty() || !self.imported.is_empty() || !self.forgotten.is_empty()
+    }
+
+    /// Merges the existing directories of `imported` into the database, then saves it.
+    fn import(&mut self, mut imported: Self) -> Result<()> {
+        imported.prune();
+        for (dir, entry) in std::mem::take(&mut imported.entries) {
+            self.merge(dir.clone(), entry);
+            self.imported.insert(dir, entry);
+        }
+
+        self.save()
+    }
+
+    /// Divides all ranks when their total is too high, forgetting the directories whose rank falls below 1.
+    fn age(&mut self) {
+        let total: f64 = self.entries.values().map(|entry| entry.rank).sum();
+        if total > MAX_TOTAL_RANK {
+            let factor = 0.9 * MAX_TOTAL_RANK / total;
+            self.entries.retain(|_, entry| {
+                entry.rank *= factor;
+                entry.rank >= 1.0
+            });
+        }
+    }
+
+    /// Adds an entry, keeping the highest rank and the latest visit if the path is already known.
+    fn merge(&mut self, path: PathBuf, entry: Entry) {
+        self.entries
+            .entry(path)
+            .and_modify(|known| {
+                known.rank = known.rank.max(entry.rank);
+                known.last_visit = known.last_visit.max(entry.last_visit);
+            })
+            .or_insert(entry);
+    }
+
+    /// Merges the lines `path|rank|timestamp` of a z data file.
+    fn merge_z(&mut self, content: &str) {
+        for line in content.lines() {
+            let mut fields = line.rsplitn(3, '|');
+            let (Some(last_visit), Some(rank), Some(path)) =
+                (fields.next(), fields.next(), fields.next())
+            else {
+                continue;
+            };
+            let (Ok(rank), Ok(last_visit)) = (rank.parse(), last_visit.parse()) else {
+                continue;
+            };
+
+            self.merge(PathBuf::from(path), Entry { rank, last_visit });
+        }
+    }
+
+    /// Merges a zoxide database: a little endian `u32` version (3), then the bincode encoding of a
+    /// `Vec` of directories, each being a path, a `f64` rank and a `u64` timestamp.
+    fn merge_zoxide(&mut self, bytes: &[u8]) -> Result<()> {
+        fn invalid() -> Error {
+            Error::new(ErrorKind::InvalidData, "invalid zoxide database")
+        }
+
+        fn take<const N: usize>(bytes: &mut &[u8]) -> Result<[u8; N]> {
+            let (head, tail) = bytes.split_first_chunk::<N>().ok_or_else(invalid)?;
+            *bytes = tail;
+            Ok(*head)
+        }
+
+        let mut bytes = bytes;
+        if u32::from_le_bytes(take(&mut bytes)?) != 3 {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "unsupported zoxide database version",
+            ));
+        }
+
+        let len = u64::from_le_bytes(take(&mut bytes)?);
+        for _ in 0..len {
+            let path_len =
+                usize::try_from(u64::from_le_bytes(take(&mut bytes)?)).map_err(|_| invalid())?;
+            if bytes.len() < path_len {
+                return Err(invalid());
+            }
+            let (path, tail) = bytes.split_at(path_len);
+            bytes = tail;
+
+            let path = std::str::from_utf8(path).map_err(|_| invalid())?;
+            let rank = f64::from_le_bytes(take(&mut bytes)?);
+            let last_visit = u64::from_le_bytes(take(&mut bytes)?);
+
+            self.merge(PathBuf::from(path), Entry { rank, last_visit });
+        }
+
+        Ok(())
+    }
+}
+
+impl Clone for Frecency {
+    /// Copies the database without the visits not saved yet, which the original saves.
+    fn clone(&self) -> Self {
+        Self {
+            path: self.path.clone(),
+            entries: self.entries.clone(),
+            visits: BTreeMap::new(),
+            imported: BTreeMap::new(),
+            forgotten: BTreeSet::new(),
+        }
+    }
+}
+
+impl Drop for Frecency {
+    fn drop(&mut self) {
+        // Errors can not be reported here, call `flush` to handle them.
+        let _ = self.flush();
+    }
+}
+
+impl FileExplorer {
+    /// Returns the frecency database of the file explorer.
+    #[inline]
+    #[must_use]
+    pub const fn frecency(&self) -> &Frecency {
+        &self.frecency
+    }
+
+    /// Saves the visits recorded in the frecency database (see [`Frecency::flush`]). They are also saved when the
+    /// file explorer is dropped.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the database can not be saved.
+    #[inline]
+    pub fn flush_frecency(&mut self) -> crate::Result<()> {
+        Ok(self.frecency.flush()?)
+    }
+
+    /// Sets the working directory to the best visited directory matching `query`
+    /// (see [`Frecency::query`]).
+    ///
+    /// # Errors
+    ///
+    /// Will return [`Error::NotFound`](crate::Error::NotFound) with `query` as path if no visited directory
+    /// matches it, or `Err` if the best match can not be listed. A best match that no longer exists is forgotten.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use ratatui_explorer::FileExplorer;
+    /// let mut file_explorer = FileExplorer::new().unwrap();
+    /// file_explorer.jump("doc").unwrap();
+    /// ```
+    pub fn jump(&mut self, query: &str) -> crate::Result<()> {
+        let best = self
+            .frecency
+            .query(query)
+            .first()
+            .map(|path| path.to_path_buf());
+        let best = best.ok_or_else(|| crate::Error::NotFound(PathBuf::from(query)))?;
+
+        if !best.is_dir() {
+            self.frecency.remove(&best);
+        }
+
+        self.set_cwd(best)
+    }
+}
+
+/// Returns `true` if the words are found in order in `haystack`, the characters of each word appearing in order.
+pub(super) fn fuzzy_match(haystack: &str, words: &[String]) -> bool {
+    let mut chars = haystack.chars();
+    words
+        .iter()
+        .all(|word| word.chars().all(|c| chars.by_ref().any(|h| h == c)))
+}
+
+/// Returns `path` as it is written in the database file, or `None` if it can not be written on a single line.
+fn encode_path(path: &Path) -> Option<&str> {
+    path.to_str().filter(|path| !path.contains(['\n', '\r']))
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use tempfile::TempDir;
+
+    use crate::{FileExplorerBuilder, Input, Outcome};
+
+    /// Build this temporary file system:
+    /// ```plaintext
+    /// <unknow>
+    /// └ root
+    ///   ├── dev
+    ///   │   └── ratatui-explorer
+    ///   └── Documents
+    /// ```
+    fn build_tmp_file_system() -> Result<TempDir> {
+        let root = TempDir::new()?;
+
+        fs::create_dir_all(root.path().join("dev/ratatui-explorer"))?;
+        fs::create_dir(root.path().join("Documents"))?;
+
+        Ok(root)
+    }
+
+    #[test]
+    fn test_query_ranks_by_frecency() -> Result<()> {
+        let root = build_tmp_file_system()?;
+        let project_path = root.path().join("dev/ratatui-explorer");
+        let documents_path = root.path().join("Documents");
+
+        let mut frecency = Frecency::new();
+        frecency.record(&documents_path);
+        frecency.record(&project_path);
+        frecency.record(&project_path);
+
+        assert_eq!(frecency.query(""), [&project_path, &documents_path]);
+        assert_eq!(frecency.query("rat exp"), [&project_path]);
+        assert_eq!(frecency.query("DOC"), [&documents_path]);
+        assert!(frecency.query("exp rat").is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_database_is_persisted_and_pruned() -> Result<()> {
+        let root = build_tmp_file_system()?;
+        let db_path = root.path().join("frecency");
+        let documents_path = root.path().join("Documents");
+        let gone_path = root.path().join("gone");
+
+        fs::write(
+            &db_path,
+            format!(
+                "{}|3|1700000000\n{}|1|1700000000\n",
+                documents_path.display(),
+                gone_path.display()
+            ),
+        )?;
+
+        let mut frecency = Frecency::open(&db_path)?;
+        assert_eq!(frecency.len(), 1);
+        assert!(frecency.is_dirty());
+        frecency.flush()?;
+        assert!(!fs::read_to_string(&db_path)?.contains("gone"));
+
+        frecency.record(&documents_path);
+        assert!(frecency.is_dirty());
+        assert_eq!(Frecency::open(&db_path)?.entries[&documents_path].rank, 3.0);
+
+        frecency.flush()?;
+        assert!(!frecency.is_dirty());
+        assert_eq!(Frecency::open(&db_path)?.entries[&documents_path].rank, 4.0);
+
+        frecency.record(&documents_path);
+        drop(frecency);
+        assert_eq!(Frecency::open(&db_path)?.entries[&documents_path].rank, 5.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_databases_sharing_a_file_add_their_visits() -> Result<()> {
+        let root = build_tmp_file_system()?;
+        let db_path = root.path().join("frecency");
+        let project_path = root.path().join("dev/ratatui-explorer");
+        let documents_path = root.path().join("Documents");
+
+        let mut first = Frecency::open(&db_path)?;
+        first.record(&documents_path);
+        first.flush()?;
+
+        let mut second = Frecency::open(&db_path)?;
+        let mut third = second.clone();
+        first.record(&documents_path);
+        second.record(&documents_path);
+        second.record(&project_path);
+        third.record(&documents_path);
+        first.flush()?;
+        second.flush()?;
+        drop(third);
+
+        let frecency = Frecency::open(&db_path)?;
+        assert_eq!(frecency.entries[&documents_path].rank, 4.0);
+        assert_eq!(frecency.entries[&project_path].rank, 1.0);
+
+        // Forgetting a directory is not undone by the other databases.
+        let mut fourth = Frecency::open(&db_path)?;
+        assert!(second.remove(&project_path));
+        second.flush()?;
+        fourth.record(&documents_path);
+        fourth.flush()?;
+        assert_eq!(Frecency::open(&db_path)?.len(), 1);
+        assert_eq!(fourth.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_failed_save_is_retried() -> Result<()> {
+        let root = build_tmp_file_system()?;
+        let db_path = root.path().join("frecency");
+        let documents_path = root.path().join("Documents");
+
+        let mut frecency = Frecency::open(&db_path)?;
+        fs::create_dir(&db_path)?;
+        frecency.record(&documents_path);
+        assert!(frecency.flush().is_err());
+        assert!(frecency.is_dirty());
+
+        fs::remove_dir(&db_path)?;
+        frecency.flush()?;
+        assert!(!frecency.is_dirty());
+        assert_eq!(Frecency::open(&db_path)?.entries[&documents_path].rank, 1.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_paths_not_fitting_on_a_line_are_not_recorded() -> Result<()> {
+        let root = build_tmp_file_system()?;
+        let db_path = root.path().join("frecency");
+        let documents_path = root.path().join("Documents");
+        let broken_path = root.path().join("two\nlines");
+        fs::create_dir(&broken_path)?;
+
+        let mut frecency = Frecency::open(&db_path)?;
+        frecency.record(&broken_path);
+        #[cfg(unix)]
+        {
+            use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
+
+            frecency.record(root.path().join(OsStr::from_bytes(b"caf\xe9")));
+        }
+        assert!(frecency.is_empty());
+
+        frecency.record(&documents_path);
+        frecency.flush()?;
+        assert_eq!(fs::read_to_string(&db_path)?.lines().count(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_import_zoxide_database() -> Result<()> {
+        let root = build_tmp_file_system()?;
+        let documents_path = root.path().join("Documents");
+        let path = documents_path.to_str().unwrap();
+
+        let mut bytes = Vec::new();
+        bytes.extend(3_u32.to_le_bytes());
+        bytes.extend(1_u64.to_le_bytes());
+        bytes.extend((path.len() as u64).to_le_bytes());
+        bytes.extend(path.as_bytes());
+        bytes.extend(12.5_f64.to_le_bytes());
+        bytes.extend(1_700_000_000_u64.to_le_bytes());
+        fs::write(root.path().join("db.zo"), &bytes)?;
+
+        let mut frecency = Frecency::new();
+        frecency.import_zoxide(root.path().join("db.zo"))?;
+        assert_eq!(
+            frecency.entries[&documents_path],
+            Entry {
+                rank: 12.5,
+                last_visit: 1_700_000_000
+            }
+        );
+
+        fs::write(root.path().join("db.zo"), &bytes[..bytes.len() - 1])?;
+        assert!(frecency.import_zoxide(root.path().join("db.zo")).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_jump_prompt_goes_to_best_match() -> Result<()> {
+        let root = build_tmp_file_system()?;
+        let project_path = root.path().join("dev/ratatui-explorer");
+
+        let mut explorer = FileExplorerBuilder::build_with_working_dir(root.path())?;
+        explorer.set_cwd(&project_path)?;
+        explorer.set_cwd(root.path().join("Documents"))?;
+
+        explorer.handle(Input::Jump)?;
+        for c in "ratex".chars() {
+            explorer.handle(Input::Char(c))?;
+        }
+        assert_eq!(
+            explorer.prompt().unwrap().candidates(),
+            [project_path.display().to_string()]
+        );
+
+        assert!(matches!(
+            explorer.handle(Input::Confirm)?,
+            Outcome::DirectoryChanged { .. }
+        ));
+        assert_eq!(*explorer.cwd(), project_path);
+
+        assert!(matches!(
+            explorer.jump("nowhere"),
+            Err(crate::Error::NotFound(query)) if query == Path::new("nowhere")
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,472 @@
+/// The git status of a [`File`](super::File).
+///
+/// The status of a directory is the most important status of the files it contains
+/// (see [`File::git_status`](super::File::git_status)).
+///
+/// Variants are ordered from the least to the most important.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum GitStatus {
+    /// The file is ignored by git.
+    Ignored,
+    /// The file is not tracked by git.
+    Untracked,
+    /// The file has changes staged for the next commit.
+    Staged,
+    /// The file has changes not staged for the next commit.
+    Modified,
+    /// The file has merge conflicts.
+    Conflicted,
+}
+
+impl GitStatus {
+    /// Returns the marker displayed next to a file with this status.
+    #[must_use]
+    pub const fn marker(self) -> char {
+        match self {
+            GitStatus::Ignored => '!',
+            GitStatus::Untracked => '?',
+            GitStatus::Staged => 'S',
+            GitStatus::Modified => 'M',
+            GitStatus::Conflicted => 'U',
+        }
+    }
+}
+
+#[cfg(feature = "git")]
+pub(super) use status::Git;
+
+#[cfg(feature = "git")]
+mod status {
+    use std::{
+        collections::HashMap,
+        ffi::OsStr,
+        path::{Path, PathBuf},
+        process::Command,
+        sync::{
+            Arc, Mutex,
+            atomic::{AtomicBool, Ordering},
+        },
+        thread,
+    };
+
+    use super::GitStatus;
+    use crate::file_explorer::{File, FileExplorer};
+
+    /// The git statuses of the working directory of a [`FileExplorer`], read by a background thread.
+    #[derive(Debug, Clone, Default)]
+    pub(in crate::file_explorer) struct Git {
+        /// The statuses last read, applied to the listings of their directory until they are read again.
+        statuses: Option<Arc<Statuses>>,
+        /// The reading of the statuses of the working directory, if any.
+        scan: Option<Arc<Scan>>,
+    }
+
+    impl Git {
+        /// Annotates `files`, listed from `dir`, with the statuses last read for `dir`, if any.
+        pub(in crate::file_explorer) fn annotate(&self, dir: &Path, files: &mut [File]) {
+            if let Some(statuses) = self
+                .statuses
+                .as_ref()
+                .filter(|statuses| statuses.dir == dir)
+            {
+                statuses.annotate(files);
+            }
+        }
+    }
+
+    #[derive(Debug, Default)]
+    struct Scan {
+        /// The statuses read, `None` inside if the directory is not inside a git repository.
+        result: Mutex<Option<Option<Statuses>>>,
+        done: AtomicBool,
+    }
+
+    /// The git statuses of the files below a directory, read with a single `git status`.
+    #[derive(Debug, Default)]
+    struct Statuses {
+        /// The directory, as it is listed.
+        dir: PathBuf,
+        /// The statuses reported by git, relative to the directory.
+        reported: HashMap<PathBuf, GitStatus>,
+        /// The most important status of the files below each directory, relative to the directory.
+        contained: HashMap<PathBuf, GitStatus>,
+        /// The status of the directory itself if it is untracked or ignored, inherited by all the files below it.
+        inherited: Option<GitStatus>,
+    }
+
+    impl Statuses {
+        /// Asks `git` the statuses of the files below `dir`, at any depth.
+        ///
+        /// Returns `None` if `dir` is not inside a git repository or if `git` can not be run.
+        fn of(dir: &Path) -> Option<Self> {
+            let canonical = dir.canonicalize().ok()?;
+            let toplevel = git(&canonical, &["rev-parse", "--show-toplevel"])?;
+            let toplevel = PathBuf::from(String::from_utf8(toplevel).ok()?.trim_end());
+            let output = git(
+                &canonical,
+                &[
+                    "status",
+                    "--porcelain=v1",
+                    "-z",
+                    "--ignored=matching",
+                    "--untracked-files=normal",
+                    "--",
+                    ".",
+                ],
+            )?;
+
+            let mut statuses = Statuses {
+                dir: dir.to_path_buf(),
+                ..Statuses::default()
+            };
+            let mut records = output
+                .split(|&b| b == 0)
+                .filter(|record| !record.is_empty());
+            while let Some(record) = records.next() {
+                let Some((code, path)) = record.split_at_checked(3) else {
+                    continue;
+                };
+                if matches!(code[0], b'R' | b'C') {
+                    // The original path of a rename or a copy follows.
+                    records.next();
+                }
+
+                let status = parse_code(code[0], code[1]);
+                let path = toplevel.join(path_from_bytes(path)?);
+                statuses.insert(&canonical, &path, status);
+            }
+
+            Some(statuses)
+        }
+
+        /// Sets the status of `files`, listed from the directory.
+        fn annotate(&self, files: &mut [File]) {
+            for file in files.iter_mut().filter(|file| !file.is_parent_entry()) {
+                file.git_status = self.get(&file.path);
+            }
+        }
+
+        /// Returns the status of `path`, a file or a directory below the directory.
+        fn get(&self, path: &Path) -> Option<GitStatus> {
+            let relative = path.strip_prefix(&self.dir).ok()?;
+
+            // The directories reported by git are untracked or ignored as a whole.
+            relative
+                .ancestors()
+                .filter(|ancestor| !ancestor.as_os_str().is_empty())
+                .filter_map(|ancestor| self.reported.get(ancestor).copied())
+                .chain(self.contained.get(relative).copied())
+                .chain(self.inherited)
+                .max()
+        }
+
+        /// Records the `status` of `path` reported by git for the directory `canonical`.
+        fn insert(&mut self, canonical: &Path, path: &Path, status: GitStatus) {
+            match path.strip_prefix(canonical) {
+                Ok(relative) if relative.as_os_str().is_empty() => {
+                    self.inherited = self.inherited.max(Some(status));
+                }
+                Ok(relative) => {
+                    self.reported.insert(relative.to_path_buf(), status);
+
+                    // An ignored file does not make its parent directories ignored.
+                    if status == GitStatus::Ignored {
+                        return;
+                    }
+                    for parent in relative.ancestors().skip(1) {
+                        if parent.as_os_str().is_empty() {
+                            break;
+                        }
+                        let contained =
+                            self.contained.entry(parent.to_path_buf()).or_insert(status);
+                        *contained = (*contained).max(status);
+                    }
+                }
+                Err(_) if canonical.starts_with(path) => {
+                    self.inherited = self.inherited.max(Some(status));
+                }
+                Err(_) => {}
+            }
+        }
+    }
+
+    impl FileExplorer {
+        /// Reads the git statuses of the working directory again in the background. They are applied to the
+        /// listing by [`poll_git_status`](FileExplorer::poll_git_status).
+        pub(in crate::file_explorer) fn scan_git_status(&mut self) {
+            let scan = Arc::new(Scan::default());
+            let thread_scan = Arc::clone(&scan);
+            let dir = self.cwd.clone();
+            thread::spawn(move || {
+                let statuses = Statuses::of(&dir);
+                if let Ok(mut result) = thread_scan.result.lock() {
+                    *result = Some(statuses);
+                }
+                thread_scan.done.store(true, Ordering::Relaxed);
+            });
+            self.git.scan = Some(scan);
+        }
+
+        /// Annotates the listing with the git statuses if they were read since the last call. Returns `true` if
+        /// they were.
+        ///
+        /// The git statuses are read by a background thread each time the working directory is listed, so that
+        /// big repositories do not freeze the file explorer. Until they are read, the files keep the statuses
+        /// last read for the working directory, if any.
+        ///
+        /// Call it regularly (eg. before drawing) while
+        /// [`is_git_status_running`](FileExplorer::is_git_status_running) returns `true`. It is also called by
+        /// [`handle`](FileExplorer::handle).
+        pub fn poll_git_status(&mut self) -> bool {
+            let Some(scan) = self
+                .git
+                .scan
+                .take_if(|scan| scan.done.load(Ordering::Relaxed))
+            else {
+                return false;
+            };
+            let Some(statuses) = scan.result.lock().ok().and_then(|mut result| result.take())
+            else {
+                return false;
+            };
+
+            self.git.statuses = statuses.map(Arc::new);
+            match &self.git.statuses {
+                Some(statuses) if statuses.dir == self.cwd => statuses.annotate(&mut self.files),
+                _ => {
+                    for file in &mut self.files {
+                        file.git_status = None;
+                    }
+                }
+            }
+            true
+        }
+
+        /// Returns `true` if the git statuses of the working directory are still being read.
+        #[inline]
+        #[must_use]
+        pub fn is_git_status_running(&self) -> bool {
+            self.git
+                .scan
+                .as_ref()
+                .is_some_and(|scan| !scan.done.load(Ordering::Relaxed))
+        }
+    }
+
+    /// Runs `git` with `args` in `dir` and returns its output if it succeeds.
+    fn git(dir: &Path, args: &[&str]) -> Option<Vec<u8>> {
+        let output = Command::new("git")
+            .arg("-C")
+            .arg(dir)
+            .args(args)
+            .output()
+            .ok()?;
+
+        output.status.success().then_some(output.stdout)
+    }
+
+    /// Parses the `XY` code of `git status --porcelain`.
+    fn parse_code(x: u8, y: u8) -> GitStatus {
+        match (x, y) {
+            (b'!', b'!') => GitStatus::Ignored,
+            (b'?', b'?') => GitStatus::Untracked,
+            (b'U', _) | (_, b'U') | (b'A', b'A') | (b'D', b'D') => GitStatus::Conflicted,
+            (_, b' ') => GitStatus::Staged,
+            _ => GitStatus::Modified,
+        }
+    }
+
+    #[cfg(unix)]
+    fn path_from_bytes(bytes: &[u8]) -> Option<PathBuf> {
+        use std::os::unix::ffi::OsStrExt;
+
+        Some(Path::new(OsStr::from_bytes(bytes)).to_path_buf())
+    }
+
+    #[cfg(not(unix))]
+    fn path_from_bytes(bytes: &[u8]) -> Option<PathBuf> {
+        std::str::from_utf8(bytes).ok().map(PathBuf::from)
+    }
+}
+
+#[cfg(all(test, feature = "git"))]
+mod tests {
+    use std::{fs, path::Path, process::Command, time::Duration};
+    use tempfile::TempDir;
+
+    use super::GitStatus;
+    use crate::{FileExplorer, FileExplorerBuilder, Result};
+
+    fn git(dir: &Path, args: &[&str]) -> Result<()> {
+        let status = Command::new("git")
+            .arg("-C")
+            .arg(dir)
+            .args(["-c", "user.name=test", "-c", "user.email=test@example.com"])
+            .args(args)
+            .status()?;
+        assert!(status.success());
+
+        Ok(())
+    }
+
+    fn wait_statuses(file_explorer: &mut FileExplorer) -> Vec<(&str, Option<GitStatus>)> {
+        while file_explorer.is_git_status_running() {
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        file_explorer.poll_git_status();
+
+        file_explorer
+            .files()
+            .iter()
+            .map(|file| (file.name.as_str(), file.git_status))
+            .collect()
+    }
+
+    /// Build this temporary git repository:
+    /// ```plaintext
+    /// <unknow>
+    /// └ root
+    ///   ├── .gitignore    (*.log, target/)
+    ///   ├── clean.txt
+    ///   ├── debug.log     ignored
+    ///   ├── modified.txt  modified
+    ///   ├── new
+    ///   │   └── file.txt  untracked
+    ///   ├── src
+    ///   │   ├── lib.rs
+    ///   │   ├── staged.rs staged
+    ///   │   └── trace.log ignored
+    ///   └── target        ignored
+    /// ```
+    fn build_tmp_repository() -> Result<TempDir> {
+        let root = TempDir::new()?;
+        let path = root.path();
+
+        git(path, &["init", "--quiet"])?;
+        fs::create_dir_all(path.join("new"))?;
+        fs::create_dir_all(path.join("src"))?;
+        fs::create_dir_all(path.join("target"))?;
+        fs::write(path.join(".gitignore"), "*.log\ntarget/\n")?;
+        fs::write(path.join("clean.txt"), "clean")?;
+        fs::write(path.join("modified.txt"), "original")?;
+        fs::write(path.join("src/lib.rs"), "")?;
+        fs::write(path.join("target/out"), "")?;
+        git(path, &["add", "."])?;
+        git(path, &["commit", "--quiet", "-m", "init"])?;
+
+        fs::write(path.join("modified.txt"), "modified")?;
+        fs::write(path.join("debug.log"), "")?;
+        fs::write(path.join("new/file.txt"), "")?;
+        fs::write(path.join("src/staged.rs"), "")?;
+        fs::write(path.join("src/trace.log"), "")?;
+        git(path, &["add", "src/staged.rs"])?;
+
+        Ok(root)
+    }
+
+    #[test]
+    fn test_files_are_annotated() -> Result<()> {
+        let root = build_tmp_repository()?;
+
+        let mut file_explorer = FileExplorerBuilder::build_with_working_dir(root.path())?;
+        assert_eq!(
+            wait_statuses(&mut file_explorer),
+            [
+                ("../", None),
+                ("new/", Some(GitStatus::Untracked)),
+                ("src/", Some(GitStatus::Staged)),
+                ("target/", Some(GitStatus::Ignored)),
+                ("clean.txt", None),
+                ("debug.log", Some(GitStatus::Ignored)),
+                ("modified.txt", Some(GitStatus::Modified)),
+            ]
+        );
+
+        file_explorer.set_cwd(root.path().join("new"))?;
+        assert_eq!(
+            wait_statuses(&mut file_explorer)[1],
+            ("file.txt", Some(GitStatus::Untracked))
+        );
+
+        file_explorer.set_cwd(root.path().join("src"))?;
+        assert_eq!(
+            wait_statuses(&mut file_explorer),
+            [
+                ("../", None),
+                ("lib.rs", None),
+                ("staged.rs", Some(GitStatus::Staged)),
+                ("trace.log", Some(GitStatus::Ignored)),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_flattened_files_are_annotated() -> Result<()> {
+        let root = build_tmp_repository()?;
+
+        let mut file_explorer = FileExplorerBuilder::build_with_working_dir(root.path())?;
+        file_explorer.set_flatten(true)?;
+        assert_eq!(
+            wait_statuses(&mut file_explorer),
+            [
+                ("../", None),
+                ("clean.txt", None),
+                ("debug.log", Some(GitStatus::Ignored)),
+                ("modified.txt", Some(GitStatus::Modified)),
+                ("new/file.txt", Some(GitStatus::Untracked)),
+                ("src/lib.rs", None),
+                ("src/staged.rs", Some(GitStatus::Staged)),
+                ("src/trace.log", Some(GitStatus::Ignored)),
+                ("target/out", Some(GitStatus::Ignored)),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_statuses_are_read_in_the_background() -> Result<()> {
+        let root = build_tmp_repository()?;
+        let status = |file_explorer: &FileExplorer, name: &str| {
+            file_explorer
+                .files()
+                .iter()
+                .find(|file| file.name == name)
+                .and_then(|file| file.git_status)
+        };
+
+        let mut file_explorer = FileExplorerBuilder::build_with_working_dir(root.path())?;
+        wait_statuses(&mut file_explorer);
+
+        // Listing the working directory again keeps the last statuses until they are read again.
+        fs::write(root.path().join("clean.txt"), "changed")?;
+        file_explorer.refresh()?;
+        assert_eq!(status(&file_explorer, "new/"), Some(GitStatus::Untracked));
+        assert_eq!(status(&file_explorer, "clean.txt"), None);
+
+        wait_statuses(&mut file_explorer);
+        assert_eq!(
+            status(&file_explorer, "clean.txt"),
+            Some(GitStatus::Modified)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_outside_of_repository() -> Result<()> {
+        let root = TempDir::new()?;
+        fs::write(root.path().join("file.txt"), "")?;
+
+        let mut file_explorer = FileExplorerBuilder::build_with_working_dir(root.path())?;
+        assert!(
+            wait_statuses(&mut file_explorer)
+                .iter()
+                .all(|(_, status)| status.is_none())
+        );
+
+        Ok(())
+    }
+}
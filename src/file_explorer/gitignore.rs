@@ -0,0 +1,142 @@
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+};
+
+use ::ignore::WalkBuilder;
+
+use super::FileExplorer;
+use crate::Result;
+
+impl FileExplorer {
+    /// Sets whether the entries matched by `.gitignore`, `.ignore` and `.git/info/exclude` are hidden.
+    ///
+    /// The ignore files of the working directory and of its parents are used, like `git` does.
+    /// Hidden files are still shown according to [`set_show_hidden`](FileExplorer::set_show_hidden).
+    ///
+    /// **Note:** This method is only available when the `gitignore` feature is enabled.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the current working directory can not be listed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use ratatui_explorer::FileExplorer;
+    /// let mut file_explorer = FileExplorer::new().unwrap();
+    /// file_explorer.set_respect_ignore(true).unwrap();
+    ///
+    /// /* `target/` and `node_modules/` are not shown anymore */
+    /// ```
+    #[inline]
+    pub fn set_respect_ignore(&mut self, respect_ignore: bool) -> Result<()> {
+        self.track(|file_explorer| {
+            file_explorer.respect_ignore = respect_ignore;
+            file_explorer.reload().map(|()| None)
+        })?;
+
+        Ok(())
+    }
+
+    /// Returns whether the entries matched by the ignore files are hidden.
+    ///
+    /// **Note:** This method is only available when the `gitignore` feature is enabled.
+    #[inline]
+    #[must_use]
+    pub const fn respect_ignore(&self) -> bool {
+        self.respect_ignore
+    }
+}
+
+/// Returns the entries of `dir` that are not matched by the ignore files.
+pub(super) fn not_ignored(dir: &Path) -> HashSet<PathBuf> {
+    WalkBuilder::new(dir)
+        .max_depth(Some(1))
+        .standard_filters(false)
+        .git_ignore(true)
+        .git_exclude(true)
+        .ignore(true)
+        .parents(true)
+        .build()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.depth() == 1)
+        .map(|entry| entry.into_path())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use tempfile::TempDir;
+
+    use crate::{FileExplorerBuilder, Input, Result};
+
+    /// Build this temporary file system:
+    /// ```plaintext
+    /// <unknow>
+    /// └ root
+    ///   ├── .git
+    ///   ├── .gitignore  (target/, *.log)
+    ///   ├── debug.log
+    ///   ├── src
+    ///   │   ├── .ignore  (generated.rs)
+    ///   │   ├── generated.rs
+    ///   │   ├── main.rs
+    ///   │   └── trace.log
+    ///   └── target
+    /// ```
+    fn build_tmp_file_system() -> Result<TempDir> {
+        let root = TempDir::new()?;
+
+        fs::create_dir_all(root.path().join(".git/info"))?;
+        fs::create_dir_all(root.path().join("src"))?;
+        fs::create_dir_all(root.path().join("target"))?;
+        fs::write(root.path().join(".gitignore"), "target/\n*.log\n")?;
+        fs::write(root.path().join("debug.log"), "")?;
+        fs::write(root.path().join("src/.ignore"), "generated.rs\n")?;
+        fs::write(root.path().join("src/generated.rs"), "")?;
+        fs::write(root.path().join("src/main.rs"), "")?;
+        fs::write(root.path().join("src/trace.log"), "")?;
+
+        Ok(root)
+    }
+
+    fn names(file_explorer: &crate::FileExplorer) -> Vec<&str> {
+        file_explorer
+            .files()
+            .iter()
+            .map(|file| file.name.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_ignored_entries_are_hidden() -> Result<()> {
+        let root = build_tmp_file_system()?;
+
+        let mut file_explorer = FileExplorerBuilder::default()
+            .working_dir(root.path())
+            .respect_ignore(true)
+            .build()?;
+        assert_eq!(names(&file_explorer), ["../", "src/"]);
+
+        file_explorer.set_show_hidden(true)?;
+        assert_eq!(
+            names(&file_explorer),
+            ["../", ".git/", "src/", ".gitignore"]
+        );
+
+        file_explorer.set_show_hidden(false)?;
+        file_explorer.set_cwd(root.path().join("src"))?;
+        assert_eq!(names(&file_explorer), ["../", "main.rs"]);
+
+        file_explorer.handle(Input::ToggleIgnored)?;
+        assert!(!file_explorer.respect_ignore());
+        assert_eq!(
+            names(&file_explorer),
+            ["../", "generated.rs", "main.rs", "trace.log"]
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,101 @@
+use std::{path::Path, sync::Arc};
+
+use super::{File, FileExplorer, Outcome};
+use crate::{Error, Result};
+
+pub(super) type CwdHook = dyn Fn(&Path, &Path) + Send + Sync + 'static;
+pub(super) type FileHook = dyn Fn(&File) + Send + Sync + 'static;
+pub(super) type ErrorHook = dyn Fn(&Error) + Send + Sync + 'static;
+
+/// Callbacks registered with the [`FileExplorerBuilder`](super::FileExplorerBuilder).
+#[derive(Clone, Default)]
+pub(super) struct Hooks {
+    pub(super) on_cwd_change: Option<Arc<CwdHook>>,
+    pub(super) on_selection_change: Option<Arc<FileHook>>,
+    pub(super) on_activate: Option<Arc<FileHook>>,
+    pub(super) on_error: Option<Arc<ErrorHook>>,
+}
+
+impl FileExplorer {
+    /// Runs `f` and notifies the hooks of what changed.
+    ///
+    /// If `f` returns `None` or [`Outcome::Nothing`], the outcome is deduced by comparing the working
+    /// directory and the selected file before and after `f`. A new working directory is recorded in the
+    /// frecency database.
+    pub(super) fn track(
+        &mut self,
+        f: impl FnOnce(&mut Self) -> Result<Option<Outcome>>,
+    ) -> Result<Outcome> {
+        let previous_cwd = self.cwd.clone();
+        let previous_selected = self.selected_path().map(Path::to_path_buf);
+
+        let outcome = match f(self) {
+            Ok(outcome) => outcome,
+            Err(err) => {
+                if let Some(on_error) = &self.hooks.on_error {
+                    on_error(&err);
+                }
+                return Err(err);
+            }
+        };
+
+        let outcome = match outcome {
+            Some(outcome) if outcome != Outcome::Nothing => outcome,
+            _ if self.cwd != previous_cwd => Outcome::DirectoryChanged {
+                from: previous_cwd,
+                to: self.cwd.clone(),
+            },
+            _ if self.selected_path() != previous_selected.as_deref() => Outcome::SelectionChanged,
+            _ => Outcome::Nothing,
+        };
+
+        if let Outcome::DirectoryChanged { to, .. } = &outcome {
+            self.frecency.record(to.clone());
+        }
+
+        self.notify(&outcome);
+
+        Ok(outcome)
+    }
+
+    /// Calls the hooks interested in `outcome`.
+    pub(super) fn notify(&self, outcome: &Outcome) {
+        let hooks = &self.hooks;
+
+        match outcome {
+            Outcome::DirectoryChanged { from, to } => {
+                if let Some(on_cwd_change) = &hooks.on_cwd_change {
+                    on_cwd_change(from, to);
+                }
+                if let Some(on_selection_change) = &hooks.on_selection_change
+                    && let Some(current) = self.try_current()
+                {
+                    on_selection_change(current);
+                }
+            }
+            Outcome::SelectionChanged => {
+                if let Some(on_selection_change) = &hooks.on_selection_change
+                    && let Some(current) = self.try_current()
+                {
+                    on_selection_change(current);
+                }
+            }
+            Outcome::FileActivated(file) => {
+                if let Some(on_activate) = &hooks.on_activate {
+                    on_activate(file);
+                }
+            }
+            Outcome::Nothing
+            | Outcome::Denied { .. }
+            | Outcome::LineActivated { .. }
+            | Outcome::Confirmed(_)
+            | Outcome::Cancelled => {}
+        }
+    }
+
+    fn selected_path(&self) -> Option<&Path> {
+        self.files
+            .get(self.selected)
+            .map(|file| file.path.as_path())
+    }
+}
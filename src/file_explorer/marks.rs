@@ -0,0 +1,133 @@
+use std::path::{Path, PathBuf};
+
+use super::FileExplorer;
+
+impl FileExplorer {
+    /// Marks the selected entry, or unmarks it if it is already marked. The parent directory entry (`../`)
+    /// can not be marked.
+    ///
+    /// Marks are kept when the working directory changes. When a [`DialogMode`](crate::DialogMode) picks a
+    /// file or a directory, confirming returns the marked entries instead of the selected one.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use ratatui_explorer::FileExplorer;
+    /// let mut file_explorer = FileExplorer::new().unwrap();
+    ///
+    /// file_explorer.toggle_mark();
+    /// assert!(file_explorer.is_marked(&file_explorer.current().path));
+    /// ```
+    pub fn toggle_mark(&mut self) {
+        let Some(file) = self.files.get(self.selected) else {
+            return;
+        };
+        if file.is_parent_entry() {
+            return;
+        }
+
+        if !self.marked.remove(&file.path) {
+            self.marked.insert(file.path.clone());
+        }
+    }
+
+    /// Marks or unmarks `path`, which does not have to be in the working directory.
+    #[inline]
+    pub fn set_marked<P: Into<PathBuf>>(&mut self, path: P, marked: bool) {
+        let path = path.into();
+        if marked {
+            self.marked.insert(path);
+        } else {
+            self.marked.remove(&path);
+        }
+    }
+
+    /// Returns `true` if `path` is marked.
+    #[inline]
+    #[must_use]
+    pub fn is_marked(&self, path: &Path) -> bool {
+        self.marked.contains(path)
+    }
+
+    /// Returns the marked paths, sorted.
+    #[inline]
+    pub fn marked(&self) -> impl Iterator<Item = &Path> {
+        self.marked.iter().map(PathBuf::as_path)
+    }
+
+    /// Unmarks all the marked paths.
+    #[inline]
+    pub fn clear_marks(&mut self) {
+        self.marked.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use tempfile::TempDir;
+
+    use crate::{DialogMode, FileExplorerBuilder, Input, Outcome, Result};
+
+    /// Build this temporary file system:
+    /// ```plaintext
+    /// <unknow>
+    /// └ root
+    ///   ├── Documents
+    ///   │   └── resume.pdf
+    ///   ├── notes.txt
+    ///   └── passport.png
+    /// ```
+    fn build_tmp_file_system() -> Result<TempDir> {
+        let root = TempDir::new()?;
+
+        fs::create_dir(root.path().join("Documents"))?;
+        fs::write(root.path().join("Documents/resume.pdf"), "")?;
+        fs::write(root.path().join("notes.txt"), "")?;
+        fs::write(root.path().join("passport.png"), "")?;
+
+        Ok(root)
+    }
+
+    #[test]
+    fn test_marks_are_returned_by_dialog() -> Result<()> {
+        let root = build_tmp_file_system()?;
+
+        let mut file_explorer = FileExplorerBuilder::default()
+            .working_dir(root.path())
+            .dialog_mode(DialogMode::OpenFile)
+            .build()?;
+
+        // `../` can not be marked.
+        file_explorer.handle(Input::Char(' '))?;
+        assert_eq!(file_explorer.marked().count(), 0);
+        assert_eq!(file_explorer.current().name, "Documents/");
+
+        file_explorer.handle(Input::Down)?;
+        file_explorer.handle(Input::Char(' '))?;
+        file_explorer.handle(Input::Char(' '))?;
+        assert_eq!(file_explorer.current().name, "passport.png");
+
+        file_explorer.set_cwd(root.path().join("Documents"))?;
+        file_explorer.handle(Input::Down)?;
+        file_explorer.handle(Input::ToggleMark)?;
+        assert!(file_explorer.is_marked(&root.path().join("notes.txt")));
+
+        let outcome = file_explorer.handle(Input::Confirm)?;
+        assert_eq!(
+            outcome,
+            Outcome::Confirmed(vec![
+                root.path().join("Documents/resume.pdf"),
+                root.path().join("notes.txt"),
+                root.path().join("passport.png"),
+            ])
+        );
+
+        file_explorer.set_marked(root.path().join("notes.txt"), false);
+        assert_eq!(file_explorer.marked().count(), 2);
+        file_explorer.clear_marks();
+        assert_eq!(file_explorer.marked().count(), 0);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,41 @@
+use std::path::PathBuf;
+
+use super::File;
+
+/// What happened when a [`FileExplorer`](crate::FileExplorer) handled an [`Input`](crate::Input).
+///
+/// Returned by [`FileExplorer::handle`](crate::FileExplorer::handle).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    /// Nothing worth reporting happened.
+    Nothing,
+    /// The selected file or directory changed, but not the working directory.
+    SelectionChanged,
+    /// The working directory changed.
+    DirectoryChanged {
+        /// The previous working directory.
+        from: PathBuf,
+        /// The new working directory.
+        to: PathBuf,
+    },
+    /// A directory can not be entered (see [`Error::PermissionDenied`](crate::Error::PermissionDenied)).
+    Denied {
+        /// The directory the user tried to enter.
+        path: PathBuf,
+        /// Why the directory can not be entered.
+        reason: String,
+    },
+    /// The user tried to open a file that is not a directory (eg. [`Input::Right`](crate::Input::Right) on a file).
+    FileActivated(File),
+    /// The user activated a result of a [content search](crate::FileExplorer::grep). The file is selected.
+    LineActivated {
+        /// The file containing the matching line.
+        file: File,
+        /// The number of the matching line, starting at 1.
+        line: usize,
+    },
+    /// The user confirmed the dialog (see [`DialogMode`](crate::DialogMode)). Contains the picked paths.
+    Confirmed(Vec<PathBuf>),
+    /// The user cancelled the dialog (see [`DialogMode`](crate::DialogMode)).
+    Cancelled,
+}
@@ -0,0 +1,503 @@
+use std::path::{Component, MAIN_SEPARATOR, PathBuf};
+
+use super::{File, FileExplorer, Filter, Outcome};
+use crate::{Error, Result, input::Input};
+
+/// The kinds of prompt a [`FileExplorer`] can show below its listing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PromptKind {
+    /// Type a path (absolute, relative to the working directory or starting with `~`) and go to it.
+    ///
+    /// [`Input::Complete`] completes the path against the entries of the typed directory.
+    GoTo,
+    /// Type a name and bookmark the working directory under it (see [`Bookmarks`](crate::Bookmarks)).
+    AddBookmark,
+    /// Type a query and jump to the best visited directory matching it (see [`Frecency`](crate::Frecency)).
+    ///
+    /// The candidates of the prompt are the best matches, updated as the query is typed.
+    Jump,
+    /// Type a text and select the next entry whose name contains it (ignoring the case).
+    ///
+    /// The matching entries stay highlighted after submitting the prompt, until it is cancelled or the working
+    /// directory changes (see [`FileExplorer::search`]).
+    Search,
+    /// Type a [fuzzy](crate::Filter::fuzzy) query and find the files below the working directory matching it
+    /// (see [`FileExplorer::find`]).
+    Find,
+    /// Type a regular expression and search the lines matching it in the files below the working directory
+    /// (see [`FileExplorer::grep`], requires the `regex` feature).
+    Grep,
+}
+
+/// A text prompt shown below the listing of a [`FileExplorer`].
+///
+/// While a prompt is open, [`FileExplorer::handle`] sends typed characters to it,
+/// [`Input::Confirm`] submits it and [`Input::Cancel`] closes it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Prompt {
+    kind: PromptKind,
+    input: String,
+    candidates: Vec<String>,
+    error: Option<String>,
+}
+
+impl Prompt {
+    fn new(kind: PromptKind) -> Self {
+        Self {
+            kind,
+            input: String::new(),
+            candidates: Vec::new(),
+            error: None,
+        }
+    }
+
+    /// Returns the kind of the prompt.
+    #[inline]
+    #[must_use]
+    pub const fn kind(&self) -> PromptKind {
+        self.kind
+    }
+
+    /// Returns the text typed in the prompt.
+    #[inline]
+    #[must_use]
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    /// Returns the candidates of the last ambiguous completion.
+    #[inline]
+    #[must_use]
+    pub fn candidates(&self) -> &[String] {
+        &self.candidates
+    }
+
+    /// Returns the error of the last submission, if it failed.
+    #[inline]
+    #[must_use]
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
+
+impl FileExplorer {
+    /// Opens a prompt below the listing. An already open prompt is replaced.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use ratatui_explorer::{FileExplorer, Input, PromptKind};
+    /// let mut file_explorer = FileExplorer::new().unwrap();
+    /// file_explorer.open_prompt(PromptKind::GoTo);
+    ///
+    /// for c in "~/Documents".chars() {
+    ///     file_explorer.handle(Input::Char(c)).unwrap();
+    /// }
+    /// file_explorer.handle(Input::Confirm).unwrap();
+    /// ```
+    #[inline]
+    pub fn open_prompt(&mut self, kind: PromptKind) {
+        self.prompt = Some(Prompt::new(kind));
+        self.update_candidates();
+    }
+
+    /// Closes the prompt, if one is open.
+    #[inline]
+    pub fn close_prompt(&mut self) {
+        self.prompt = None;
+    }
+
+    /// Returns the open prompt, if any.
+    #[inline]
+    #[must_use]
+    pub const fn prompt(&self) -> Option<&Prompt> {
+        self.prompt.as_ref()
+    }
+
+    /// Handles `input` if a prompt is open.
+    ///
+    /// Returns `None` if no prompt is open.
+    pub(super) fn handle_prompt(&mut self, input: Input) -> Result<Option<Outcome>> {
+        let Some(prompt) = self.prompt.as_mut() else {
+            return Ok(None);
+        };
+
+        match input {
+            Input::Char(c) => {
+                prompt.input.push(c);
+                prompt.error = None;
+                self.update_candidates();
+            }
+            Input::Backspace => {
+                prompt.input.pop();
+                prompt.error = None;
+                self.update_candidates();
+            }
+            Input::Cancel => {
+                if prompt.kind == PromptKind::Search {
+                    self.search = None;
+                }
+                self.prompt = None;
+            }
+            Input::Complete if prompt.kind == PromptKind::GoTo => self.complete_prompt(),
+            Input::Confirm => return self.submit_prompt().map(Some),
+            _ => (),
+        }
+
+        Ok(Some(Outcome::Nothing))
+    }
+
+    fn submit_prompt(&mut self) -> Result<Outcome> {
+        let Some(prompt) = self.prompt.as_ref() else {
+            return Ok(Outcome::Nothing);
+        };
+
+        match prompt.kind {
+            PromptKind::GoTo if prompt.input.is_empty() => self.prompt = None,
+            PromptKind::GoTo => {
+                let path = self.resolve_path(&prompt.input);
+
+                let result = if path.is_dir() {
+                    self.load_cwd(path)
+                } else if path.exists() {
+                    self.load_working_file(path)
+                } else {
+                    Err(Error::NotFound(path))
+                };
+
+                self.close_prompt_or_show(result);
+            }
+            PromptKind::AddBookmark if prompt.input.is_empty() => self.prompt = None,
+            PromptKind::AddBookmark => {
+                let name = prompt.input.clone();
+                let result = self.add_bookmark(name);
+                self.close_prompt_or_show(result);
+            }
+            PromptKind::Jump => {
+                let query = prompt.input.clone();
+                let result = self.jump(&query);
+                self.close_prompt_or_show(result);
+            }
+            PromptKind::Search => self.prompt = None,
+            PromptKind::Find if prompt.input.is_empty() => self.prompt = None,
+            PromptKind::Find => {
+                let pattern = Filter::fuzzy(&prompt.input);
+                self.prompt = None;
+                self.find(pattern, None);
+            }
+            PromptKind::Grep if prompt.input.is_empty() => self.prompt = None,
+            PromptKind::Grep => {
+                #[cfg(feature = "regex")]
+                let result = {
+                    let pattern = prompt.input.clone();
+                    self.grep(&pattern, None)
+                };
+                #[cfg(not(feature = "regex"))]
+                let result = Err(Error::Filter(
+                    "searching the content of the files requires the `regex` feature".to_owned(),
+                ));
+                self.close_prompt_or_show(result);
+            }
+        }
+
+        Ok(Outcome::Nothing)
+    }
+
+    /// Closes the prompt if `result` is `Ok`, otherwise shows the error in it.
+    fn close_prompt_or_show(&mut self, result: Result<()>) {
+        match result {
+            Ok(()) => self.prompt = None,
+            Err(err) => {
+                if let Some(prompt) = self.prompt.as_mut() {
+                    prompt.error = Some(err.to_string());
+                }
+            }
+        }
+    }
+
+    /// Updates the candidates of prompts listing them as the input is typed.
+    fn update_candidates(&mut self) {
+        const MAX_CANDIDATES: usize = 5;
+
+        let Some(prompt) = self.prompt.as_mut() else {
+            return;
+        };
+
+        match prompt.kind {
+            PromptKind::Jump => {
+                prompt.candidates = self
+                    .frecency
+                    .query(&prompt.input)
+                    .into_iter()
+                    .take(MAX_CANDIDATES)
+                    .map(|path| path.display().to_string())
+                    .collect();
+            }
+            PromptKind::Search => {
+                let query = prompt.input.clone();
+                self.search_for(query);
+            }
+            PromptKind::GoTo | PromptKind::AddBookmark | PromptKind::Find | PromptKind::Grep => {}
+        }
+    }
+
+    /// Returns the text searched in the working directory, if any (see [`PromptKind::Search`]).
+    #[inline]
+    #[must_use]
+    pub fn search(&self) -> Option<&str> {
+        self.search.as_deref()
+    }
+
+    /// Returns `true` if the name of `file` contains the searched text.
+    pub(crate) fn is_search_match(&self, file: &File) -> bool {
+        let Some(query) = self.search.as_deref() else {
+            return false;
+        };
+
+        !file.is_parent_entry()
+            && file
+                .file_name
+                .to_string_lossy()
+                .to_lowercase()
+                .contains(&query.to_lowercase())
+    }
+
+    /// Searches `query` and selects the first match, starting from the selected entry.
+    fn search_for(&mut self, query: String) {
+        self.search = (!query.is_empty()).then_some(query);
+
+        let len = self.files.len();
+        if let Some(idx) = (0..len)
+            .map(|offset| (self.selected + offset) % len)
+            .find(|&idx| self.is_search_match(&self.files[idx]))
+        {
+            self.selected = idx;
+        }
+    }
+
+    /// Completes the typed path against the entries of its directory.
+    fn complete_prompt(&mut self) {
+        let Some(prompt) = self.prompt.as_ref() else {
+            return;
+        };
+
+        let (dir, prefix) = match prompt.input.rfind(['/', MAIN_SEPARATOR]) {
+            Some(idx) => prompt.input.split_at(idx + 1),
+            None => ("", prompt.input.as_str()),
+        };
+        let show_hidden = self.show_hidden || prefix.starts_with('.');
+
+        let mut candidates: Vec<String> = std::fs::read_dir(self.resolve_path(dir))
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| {
+                let entry = entry.ok()?;
+                let name = entry.file_name().into_string().ok()?;
+                if !name.starts_with(prefix) || (!show_hidden && name.starts_with('.')) {
+                    return None;
+                }
+
+                if entry.path().is_dir() {
+                    Some(format!("{name}/"))
+                } else {
+                    Some(name)
+                }
+            })
+            .collect();
+        candidates.sort_unstable();
+
+        let completed = match candidates.as_slice() {
+            [] => return,
+            [candidate] => candidate.clone(),
+            [first, others @ ..] => others.iter().fold(first.clone(), |common, candidate| {
+                let len = common
+                    .char_indices()
+                    .zip(candidate.chars())
+                    .take_while(|((_, a), b)| a == b)
+                    .last()
+                    .map_or(0, |((idx, a), _)| idx + a.len_utf8());
+                common[..len].to_owned()
+            }),
+        };
+
+        let input = format!("{dir}{completed}");
+        if let Some(prompt) = self.prompt.as_mut() {
+            prompt.input = input;
+            prompt.candidates = if candidates.len() > 1 {
+                candidates
+            } else {
+                Vec::new()
+            };
+            prompt.error = None;
+        }
+    }
+
+    /// Resolves a typed path: `~` is expanded, relative paths are relative to the working directory
+    /// and `.` and `..` components are removed.
+    pub(super) fn resolve_path(&self, path: &str) -> PathBuf {
+        let path = match path.strip_prefix('~') {
+            Some(rest) if rest.is_empty() || rest.starts_with(['/', MAIN_SEPARATOR]) => {
+                let home = std::env::home_dir().unwrap_or_default();
+                home.join(rest.trim_start_matches(['/', MAIN_SEPARATOR]))
+            }
+            _ => self.cwd.join(path),
+        };
+
+        path.components()
+            .fold(PathBuf::new(), |mut resolved, component| {
+                match component {
+                    Component::CurDir => {}
+                    Component::ParentDir => {
+                        resolved.pop();
+                    }
+                    component => resolved.push(component),
+                }
+                resolved
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::fs::{self, File};
+    use tempfile::TempDir;
+
+    use crate::FileExplorerBuilder;
+
+    /// Build this temporary file system:
+    /// ```plaintext
+    /// <unknow>
+    /// └ root
+    ///   ├── .git
+    ///   └── Documents
+    ///       ├── passport.png
+    ///       └── resume.pdf
+    /// ```
+    fn build_tmp_file_system() -> Result<TempDir> {
+        let root = TempDir::new()?;
+
+        let git_path = root.path().join(".git");
+        let documents_path = root.path().join("Documents");
+        let passport_path = root.path().join("Documents/passport.png");
+        let resume_path = root.path().join("Documents/resume.pdf");
+
+        fs::create_dir(git_path)?;
+        fs::create_dir(documents_path)?;
+        File::create(passport_path)?;
+        File::create(resume_path)?;
+
+        Ok(root)
+    }
+
+    fn type_text(file_explorer: &mut FileExplorer, text: &str) -> Result<()> {
+        for c in text.chars() {
+            file_explorer.handle(Input::Char(c))?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_goto_relative_path_selects_file() -> Result<()> {
+        let root = build_tmp_file_system()?;
+        let documents_path = root.path().join("Documents");
+
+        let mut explorer = FileExplorerBuilder::build_with_working_dir(root.path())?;
+        explorer.handle(Input::GoTo)?;
+        type_text(&mut explorer, "Documents/./resume.pdf")?;
+
+        assert_eq!(
+            explorer.handle(Input::Confirm)?,
+            Outcome::DirectoryChanged {
+                from: root.path().to_path_buf(),
+                to: documents_path.clone(),
+            }
+        );
+        assert_eq!(explorer.current().path, documents_path.join("resume.pdf"));
+        assert_eq!(explorer.prompt(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_goto_shows_error_inline() -> Result<()> {
+        let root = build_tmp_file_system()?;
+
+        let mut explorer = FileExplorerBuilder::build_with_working_dir(root.path())?;
+        explorer.open_prompt(PromptKind::GoTo);
+        type_text(&mut explorer, "Documents/../nowhere")?;
+
+        assert_eq!(explorer.handle(Input::Confirm)?, Outcome::Nothing);
+        assert_eq!(*explorer.cwd(), root.path());
+
+        let error = explorer.prompt().and_then(Prompt::error).unwrap();
+        assert!(error.contains(&root.path().join("nowhere").display().to_string()));
+
+        explorer.handle(Input::Cancel)?;
+        assert_eq!(explorer.prompt(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_complete_unique_candidate() -> Result<()> {
+        let root = build_tmp_file_system()?;
+
+        let mut explorer = FileExplorerBuilder::build_with_working_dir(root.path())?;
+        explorer.open_prompt(PromptKind::GoTo);
+        type_text(&mut explorer, "Doc")?;
+        explorer.handle(Input::Complete)?;
+        type_text(&mut explorer, "pa")?;
+        explorer.handle(Input::Complete)?;
+
+        let prompt = explorer.prompt().unwrap();
+        assert_eq!(prompt.input(), "Documents/passport.png");
+        assert!(prompt.candidates().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_complete_lists_ambiguous_candidates() -> Result<()> {
+        let root = build_tmp_file_system()?;
+        File::create(root.path().join("Documents/passwords.txt"))?;
+
+        let mut explorer = FileExplorerBuilder::build_with_working_dir(root.path())?;
+        explorer.open_prompt(PromptKind::GoTo);
+        type_text(&mut explorer, "Documents/p")?;
+        explorer.handle(Input::Complete)?;
+
+        let prompt = explorer.prompt().unwrap();
+        assert_eq!(prompt.input(), "Documents/pass");
+        assert_eq!(prompt.candidates(), ["passport.png", "passwords.txt"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_search_selects_and_highlights_matches() -> Result<()> {
+        let root = build_tmp_file_system()?;
+
+        let mut explorer =
+            FileExplorerBuilder::build_with_working_dir(root.path().join("Documents"))?;
+        explorer.handle(Input::Char('/'))?;
+        type_text(&mut explorer, "RES")?;
+        assert_eq!(explorer.current().name, "resume.pdf");
+        assert_eq!(explorer.search(), Some("RES"));
+
+        explorer.handle(Input::Confirm)?;
+        assert!(explorer.prompt().is_none());
+        let matches: Vec<_> = explorer
+            .files()
+            .iter()
+            .map(|file| explorer.is_search_match(file))
+            .collect();
+        assert_eq!(matches, [false, false, true]);
+
+        explorer.handle(Input::Search)?;
+        explorer.handle(Input::Cancel)?;
+        assert_eq!(explorer.search(), None);
+
+        Ok(())
+    }
+}
@@ -15,17 +15,52 @@ impl From<&Event> for Input {
             )
         {
             let input = match key.code {
-                KeyCode::Char('j') | KeyCode::Down => Input::Down,
-                KeyCode::Char('k') | KeyCode::Up => Input::Up,
-                KeyCode::Left | KeyCode::Backspace => Input::Left,
-                KeyCode::Char('h') => {
-                    if key.modifiers.contains(KeyModifiers::CONTROL) {
-                        Input::ToggleShowHidden
-                    } else {
-                        Input::Left
-                    }
-                }
-                KeyCode::Char('l') | KeyCode::Right | KeyCode::Enter => Input::Right,
+                KeyCode::Down => Input::Down,
+                KeyCode::Up => Input::Up,
+                KeyCode::Left => Input::Left,
+                KeyCode::Right => Input::Right,
+                KeyCode::Backspace => Input::Backspace,
+                KeyCode::Enter => Input::Confirm,
+                KeyCode::Esc => Input::Cancel,
+                KeyCode::Tab => Input::Complete,
+                KeyCode::Delete => Input::Delete,
+                KeyCode::Char('h') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    Input::ToggleShowHidden
+                }
+                KeyCode::Char('l') if key.modifiers.contains(KeyModifiers::CONTROL) => Input::GoTo,
+                KeyCode::Char('b') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    Input::AddBookmark
+                }
+                KeyCode::Char('o') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    Input::Bookmarks
+                }
+                KeyCode::Char('g') if key.modifiers.contains(KeyModifiers::CONTROL) => Input::Jump,
+                KeyCode::Char('f') if key.modifiers.contains(KeyModifiers::CONTROL) => Input::Find,
+                KeyCode::Char('w') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    Input::SwitchPane
+                }
+                KeyCode::Char('f') if key.modifiers.contains(KeyModifiers::ALT) => Input::Grep,
+                KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::ALT) => {
+                    Input::ToggleFlatten
+                }
+                KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::ALT) => {
+                    Input::ToggleDiskUsage
+                }
+                KeyCode::Char('t') if key.modifiers.contains(KeyModifiers::ALT) => Input::NewTab,
+                KeyCode::Char('x') if key.modifiers.contains(KeyModifiers::ALT) => Input::CloseTab,
+                KeyCode::Char('n') if key.modifiers.contains(KeyModifiers::ALT) => Input::NextTab,
+                KeyCode::Char('p') if key.modifiers.contains(KeyModifiers::ALT) => {
+                    Input::PreviousTab
+                }
+                KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::ALT) => {
+                    Input::DuplicateTab
+                }
+                KeyCode::Char('i') if key.modifiers.contains(KeyModifiers::ALT) => {
+                    Input::ToggleIgnored
+                }
+                KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    Input::Char(c)
+                }
                 KeyCode::Home => Input::Home,
                 KeyCode::End => Input::End,
                 KeyCode::PageUp => Input::PageUp,
@@ -7,7 +7,11 @@ mod termion;
 #[cfg(feature = "termwiz")]
 mod termwiz;
 
-/// Input enum to represent the fours different actions available inside a [`FileExplorer`](crate::FileExplorer).
+/// Input enum to represent the different actions available inside a [`FileExplorer`](crate::FileExplorer).
+///
+/// Keys that can also be typed into a text field (letters, `<Backspace>`, `<Enter>`) are reported as
+/// [`Char`](Input::Char), [`Backspace`](Input::Backspace) and [`Confirm`](Input::Confirm). The file explorer
+/// gives them their navigation meaning (eg. `j` moves down) when no text field is focused.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Input {
     /// Move the selection up.
@@ -26,11 +30,72 @@ pub enum Input {
     Left,
     /// Go to the child directory (if the selected item is a directory).
     Right,
+    /// Confirm the selected item. Behaves like [`Right`](Input::Right) unless a dialog mode gives it another meaning.
+    Confirm,
+    /// Cancel the current dialog or text field.
+    Cancel,
+    /// A character typed by the user.
+    Char(char),
+    /// Delete the last typed character. Behaves like [`Left`](Input::Left) when no text field is focused.
+    Backspace,
     /// Toggle between showing hidden files or not.
     ToggleShowHidden,
+    /// Toggle between hiding the files matched by `.gitignore` or not (requires the `gitignore` feature).
+    ToggleIgnored,
+    /// Open the prompt to go to a typed path.
+    GoTo,
+    /// Complete the text typed in the prompt.
+    Complete,
+    /// Open the prompt to bookmark the working directory.
+    AddBookmark,
+    /// Open the bookmarks popup.
+    Bookmarks,
+    /// Open the prompt to jump to a frequently and recently visited directory.
+    Jump,
+    /// Delete the selected item (eg. a bookmark in the bookmarks popup).
+    Delete,
+    /// Mark the selected item, or unmark it if it is already marked, and move the selection down.
+    ToggleMark,
+    /// Open the prompt to search the entries of the working directory by name.
+    Search,
+    /// Open the prompt to find the files below the working directory.
+    Find,
+    /// Open the prompt to search the content of the files below the working directory (requires the `regex` feature).
+    Grep,
+    /// Toggle between flattening the listing of the working directory or not.
+    ToggleFlatten,
+    /// Toggle between showing the disk usage of the entries of the working directory or not.
+    ToggleDiskUsage,
+    /// Give the focus to the other pane of a [`DualPane`](crate::DualPane).
+    SwitchPane,
+    /// Open a new tab of an [`ExplorerTabs`](crate::ExplorerTabs).
+    NewTab,
+    /// Close the shown tab of an [`ExplorerTabs`](crate::ExplorerTabs).
+    CloseTab,
+    /// Show the next tab of an [`ExplorerTabs`](crate::ExplorerTabs).
+    NextTab,
+    /// Show the previous tab of an [`ExplorerTabs`](crate::ExplorerTabs).
+    PreviousTab,
+    /// Open a copy of the shown tab of an [`ExplorerTabs`](crate::ExplorerTabs).
+    DuplicateTab,
     /// Do nothing (used for converting events from other libraries, like
     /// [crossterm](https://docs.rs/crossterm/latest/crossterm/event/enum.Event.html),
     /// [termion](https://docs.rs/termion/latest/termion/event/enum.Event.html) and
     /// [termwiz](https://docs.rs/termwiz/latest/termwiz/input/enum.InputEvent.html) to [`Input`]).
     None,
 }
+
+impl Input {
+    /// Returns the navigation action bound to a text key, used when no text field is focused.
+    pub(crate) const fn as_navigation(self) -> Self {
+        match self {
+            Input::Char('j') => Input::Down,
+            Input::Char('k') => Input::Up,
+            Input::Char('h') | Input::Backspace => Input::Left,
+            Input::Char('l') | Input::Confirm => Input::Right,
+            Input::Char(' ') => Input::ToggleMark,
+            Input::Char('/') => Input::Search,
+            input => input,
+        }
+    }
+}
@@ -9,15 +9,36 @@ impl From<&Event> for Input {
     fn from(value: &Event) -> Self {
         match value {
             Event::Key(key) => match key {
-                Key::Char('j') | Key::Down => Input::Down,
-                Key::Char('k') | Key::Up => Input::Up,
-                Key::Char('h') | Key::Left | Key::Backspace => Input::Left,
-                Key::Char('l') | Key::Right | Key::Char('\n') => Input::Right,
+                Key::Down => Input::Down,
+                Key::Up => Input::Up,
+                Key::Left => Input::Left,
+                Key::Right => Input::Right,
+                Key::Backspace => Input::Backspace,
+                Key::Char('\n') => Input::Confirm,
+                Key::Char('\t') => Input::Complete,
+                Key::Esc => Input::Cancel,
+                Key::Char(c) => Input::Char(*c),
                 Key::Home => Input::Home,
                 Key::End => Input::End,
                 Key::PageUp => Input::PageUp,
                 Key::PageDown => Input::PageDown,
                 Key::Ctrl('h') => Input::ToggleShowHidden,
+                Key::Ctrl('l') => Input::GoTo,
+                Key::Ctrl('b') => Input::AddBookmark,
+                Key::Ctrl('o') => Input::Bookmarks,
+                Key::Ctrl('g') => Input::Jump,
+                Key::Ctrl('f') => Input::Find,
+                Key::Ctrl('w') => Input::SwitchPane,
+                Key::Alt('f') => Input::Grep,
+                Key::Alt('i') => Input::ToggleIgnored,
+                Key::Alt('r') => Input::ToggleFlatten,
+                Key::Alt('u') => Input::ToggleDiskUsage,
+                Key::Alt('t') => Input::NewTab,
+                Key::Alt('x') => Input::CloseTab,
+                Key::Alt('n') => Input::NextTab,
+                Key::Alt('p') => Input::PreviousTab,
+                Key::Alt('d') => Input::DuplicateTab,
+                Key::Delete => Input::Delete,
                 _ => Input::None,
             },
             _ => Input::None,
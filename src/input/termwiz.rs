@@ -9,18 +9,40 @@ impl From<&InputEvent> for Input {
     fn from(value: &InputEvent) -> Self {
         match value {
             InputEvent::Key(key) => match key.key {
-                KeyCode::Char('j') | KeyCode::DownArrow => Input::Down,
-                KeyCode::Char('k') | KeyCode::UpArrow => Input::Up,
-                KeyCode::LeftArrow | KeyCode::Backspace => Input::Left,
-                KeyCode::Char('h') => {
-                    if key.modifiers.contains(Modifiers::CTRL) {
-                        Input::ToggleShowHidden
-                    } else {
-                        Input::Left
-                    }
+                KeyCode::DownArrow => Input::Down,
+                KeyCode::UpArrow => Input::Up,
+                KeyCode::LeftArrow => Input::Left,
+                KeyCode::RightArrow => Input::Right,
+                KeyCode::Backspace => Input::Backspace,
+                KeyCode::Enter => Input::Confirm,
+                KeyCode::Escape => Input::Cancel,
+                KeyCode::Tab => Input::Complete,
+                KeyCode::Delete => Input::Delete,
+                KeyCode::Char('h') if key.modifiers.contains(Modifiers::CTRL) => {
+                    Input::ToggleShowHidden
                 }
-
-                KeyCode::Char('l') | KeyCode::RightArrow | KeyCode::Enter => Input::Right,
+                KeyCode::Char('l') if key.modifiers.contains(Modifiers::CTRL) => Input::GoTo,
+                KeyCode::Char('b') if key.modifiers.contains(Modifiers::CTRL) => Input::AddBookmark,
+                KeyCode::Char('o') if key.modifiers.contains(Modifiers::CTRL) => Input::Bookmarks,
+                KeyCode::Char('g') if key.modifiers.contains(Modifiers::CTRL) => Input::Jump,
+                KeyCode::Char('f') if key.modifiers.contains(Modifiers::CTRL) => Input::Find,
+                KeyCode::Char('w') if key.modifiers.contains(Modifiers::CTRL) => Input::SwitchPane,
+                KeyCode::Char('f') if key.modifiers.contains(Modifiers::ALT) => Input::Grep,
+                KeyCode::Char('r') if key.modifiers.contains(Modifiers::ALT) => {
+                    Input::ToggleFlatten
+                }
+                KeyCode::Char('u') if key.modifiers.contains(Modifiers::ALT) => {
+                    Input::ToggleDiskUsage
+                }
+                KeyCode::Char('t') if key.modifiers.contains(Modifiers::ALT) => Input::NewTab,
+                KeyCode::Char('x') if key.modifiers.contains(Modifiers::ALT) => Input::CloseTab,
+                KeyCode::Char('n') if key.modifiers.contains(Modifiers::ALT) => Input::NextTab,
+                KeyCode::Char('p') if key.modifiers.contains(Modifiers::ALT) => Input::PreviousTab,
+                KeyCode::Char('d') if key.modifiers.contains(Modifiers::ALT) => Input::DuplicateTab,
+                KeyCode::Char('i') if key.modifiers.contains(Modifiers::ALT) => {
+                    Input::ToggleIgnored
+                }
+                KeyCode::Char(c) if !key.modifiers.contains(Modifiers::CTRL) => Input::Char(c),
                 KeyCode::Home => Input::Home,
                 KeyCode::End => Input::End,
                 KeyCode::PageUp => Input::PageUp,
@@ -3,15 +3,31 @@
 //! - `crossterm` (default): Enables the [`From<&Event>`](enum.Input.html#method.from-2) implementation for [`Input`].
 //! - `termion`: Enables the [`From<&Event>`](enum.Input.html#method.from-1) implementation for [`Input`].
 //! - `termwiz`: Enables the [`From<&InputEvent>`](enum.Input.html#method.from) implementation for [`Input`].
+//! - `glob`: Enables [`Filter::glob`].
+//! - `regex`: Enables [`Filter::regex`] and searching the content of the files (see [`FileExplorer::grep`]).
+//! - `git`: Annotates the files with their [`GitStatus`], using the local `git` binary in the background
+//!   (see [`FileExplorer::poll_git_status`]).
+//! - `gitignore`: Enables hiding the files matched by `.gitignore` (see [`FileExplorer::set_respect_ignore`]).
 
 #![forbid(unsafe_code)]
 #![warn(missing_docs)]
 #![warn(rustdoc::missing_crate_level_docs)]
 #![warn(rustdoc::unescaped_backticks)]
+mod dual_pane;
+mod error;
 mod file_explorer;
 mod input;
+mod places;
+mod tabs;
 mod widget;
 
-pub use file_explorer::{File, FileExplorer, FileExplorerBuilder};
+pub use dual_pane::{DualPane, Pane};
+pub use error::{Error, Result};
+pub use file_explorer::{
+    Bookmarks, DialogMode, File, FileExplorer, FileExplorerBuilder, FileKind, Filter, Frecency,
+    GitStatus, NameDecoration, Outcome, Prompt, PromptKind,
+};
 pub use input::Input;
-pub use widget::Theme;
+pub use places::{Place, PlaceKind, Places};
+pub use tabs::ExplorerTabs;
+pub use widget::{Icon, Icons, RowContext, Theme};
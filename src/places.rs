@@ -0,0 +1,474 @@
+use std::path::{Path, PathBuf};
+
+use ratatui::widgets::WidgetRef;
+
+use crate::{Bookmarks, FileExplorer, Input, Outcome, Result, widget::PlacesRenderer};
+
+/// The kind of a [`Place`], telling where it comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PlaceKind {
+    /// The home directory of the user.
+    Home,
+    /// A directory of the user listed in `user-dirs.dirs` (eg. `Documents` or `Downloads`).
+    UserDir,
+    /// A mounted filesystem.
+    Mount,
+    /// A [bookmark](crate::Bookmarks).
+    Bookmark,
+    /// A place added by the application.
+    Custom,
+}
+
+/// A directory shown in the [`Places`] sidebar.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Place {
+    /// The name shown in the sidebar.
+    pub name: String,
+    /// The directory the place leads to.
+    pub path: PathBuf,
+    /// Where the place comes from.
+    pub kind: PlaceKind,
+}
+
+impl Place {
+    /// Creates a new place.
+    pub fn new<S: Into<String>, P: Into<PathBuf>>(name: S, path: P, kind: PlaceKind) -> Self {
+        Self {
+            name: name.into(),
+            path: path.into(),
+            kind,
+        }
+    }
+}
+
+/// A sidebar listing places to go to in a [`FileExplorer`]: the home directory, the user directories,
+/// the mounted filesystems and the bookmarks.
+///
+/// The sidebar is drawn next to the file explorer with [`widget`](Places::widget), and receives the inputs
+/// with [`handle`](Places::handle), which forwards them to the file explorer when the sidebar is not focused.
+///
+/// # Examples
+///
+/// ```no_run
+/// use ratatui::{layout::{Constraint, Layout}, widgets::FrameExt};
+/// use ratatui_explorer::{FileExplorer, Places};
+///
+/// let mut file_explorer = FileExplorer::new().unwrap();
+/// let mut places = Places::new().with_bookmarks(file_explorer.bookmarks());
+///
+/// # fn draw(f: &mut ratatui::Frame, places: &Places, file_explorer: &FileExplorer) {
+/// let [sidebar, listing] =
+///     Layout::horizontal([Constraint::Length(24), Constraint::Fill(1)]).areas(f.area());
+/// f.render_widget_ref(places.widget(file_explorer), sidebar);
+/// f.render_widget_ref(file_explorer.widget(), listing);
+/// # }
+/// # fn get_event() -> ratatui_explorer::Input { ratatui_explorer::Input::None }
+///
+/// // `<Tab>` moves the focus between the sidebar and the file explorer.
+/// places.handle(get_event(), &mut file_explorer).unwrap();
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Places {
+    places: Vec<Place>,
+    selected: usize,
+    focused: bool,
+}
+
+impl Places {
+    /// Creates a sidebar listing the home directory, the user directories and the mounted filesystems.
+    ///
+    /// The user directories are read from `$XDG_CONFIG_HOME/user-dirs.dirs` (`~/.config/user-dirs.dirs` by
+    /// default) and the mounted filesystems from `/proc/self/mountinfo`, skipping the virtual ones
+    /// (eg. `/proc` or `/sys`). The missing files are ignored.
+    #[must_use]
+    pub fn new() -> Self {
+        let mut places = Self::empty();
+
+        if let Some(home) = std::env::home_dir() {
+            let config_home = std::env::var_os("XDG_CONFIG_HOME")
+                .map(PathBuf::from)
+                .filter(|path| path.is_absolute())
+                .unwrap_or_else(|| home.join(".config"));
+            let user_dirs = std::fs::read_to_string(config_home.join("user-dirs.dirs"))
+                .map(|content| parse_user_dirs(&content, &home))
+                .unwrap_or_default();
+
+            places = places.with_place(Place::new("Home", &home, PlaceKind::Home));
+            for dir in user_dirs {
+                places = places.with_place(Place::new(name_of(&dir), dir, PlaceKind::UserDir));
+            }
+        }
+
+        let mounts = std::fs::read_to_string("/proc/self/mountinfo")
+            .map(|content| parse_mountinfo(&content))
+            .unwrap_or_default();
+        for mount in mounts {
+            places = places.with_place(Place::new(name_of(&mount), mount, PlaceKind::Mount));
+        }
+
+        places
+    }
+
+    /// Creates an empty sidebar.
+    #[must_use]
+    pub const fn empty() -> Self {
+        Self {
+            places: Vec::new(),
+            selected: 0,
+            focused: false,
+        }
+    }
+
+    /// Adds `place` at the end of the sidebar.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_place(mut self, place: Place) -> Self {
+        self.places.push(place);
+        self
+    }
+
+    /// Adds the bookmarks at the end of the sidebar, named after their name.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_bookmarks(mut self, bookmarks: &Bookmarks) -> Self {
+        self.extend_bookmarks(bookmarks);
+        self
+    }
+
+    /// Replaces the bookmarks of the sidebar by `bookmarks` (eg. after the user added one).
+    pub fn set_bookmarks(&mut self, bookmarks: &Bookmarks) {
+        self.places
+            .retain(|place| place.kind != PlaceKind::Bookmark);
+        self.extend_bookmarks(bookmarks);
+        self.selected = self.selected.min(self.places.len().saturating_sub(1));
+    }
+
+    fn extend_bookmarks(&mut self, bookmarks: &Bookmarks) {
+        self.places.extend(
+            bookmarks
+                .iter()
+                .map(|(name, path)| Place::new(name, path, PlaceKind::Bookmark)),
+        );
+    }
+
+    /// Returns the places of the sidebar.
+    #[inline]
+    #[must_use]
+    pub fn places(&self) -> &[Place] {
+        &self.places
+    }
+
+    /// Returns the selected place, if the sidebar is not empty.
+    #[inline]
+    #[must_use]
+    pub fn selected(&self) -> Option<&Place> {
+        self.places.get(self.selected)
+    }
+
+    /// Returns the index of the selected place.
+    #[inline]
+    #[must_use]
+    pub const fn selected_idx(&self) -> usize {
+        self.selected
+    }
+
+    /// Selects the place at `selected`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `selected` is out of bounds.
+    #[inline]
+    pub fn set_selected_idx(&mut self, selected: usize) {
+        assert!(selected < self.places.len());
+        self.selected = selected;
+    }
+
+    /// Returns `true` if the sidebar has the focus, rather than the file explorer.
+    #[inline]
+    #[must_use]
+    pub const fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    /// Gives the focus to the sidebar, or to the file explorer if `focused` is `false`.
+    #[inline]
+    pub const fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+
+    /// Returns a widget rendering the sidebar, highlighting the place of the working directory of `file_explorer`
+    /// and styled with its [`Theme`](crate::Theme).
+    #[inline]
+    #[must_use]
+    pub const fn widget<'a>(&'a self, file_explorer: &'a FileExplorer) -> impl WidgetRef + 'a {
+        PlacesRenderer(self, file_explorer)
+    }
+
+    /// Handles the input from the user, for the sidebar if it is focused or for `file_explorer` otherwise.
+    ///
+    /// `Complete` (`<Tab>`) moves the focus between the sidebar and the file explorer, unless a prompt of the file
+    /// explorer is open. While the sidebar is focused:
+    /// - `Up`, `Down`, `Home`, `End`, `PageUp` and `PageDown` (and `j` and `k`) move the selection.
+    /// - `Right` and `Confirm` (and `l`) go to the selected place in `file_explorer` with
+    ///   [`set_cwd`](FileExplorer::set_cwd) and give the focus back to the file explorer.
+    /// - `Left` and `Cancel` (and `h`) give the focus back to the file explorer.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the file explorer returns one (see [`FileExplorer::handle`]).
+    pub fn handle<I: Into<Input>>(
+        &mut self,
+        input: I,
+        file_explorer: &mut FileExplorer,
+    ) -> Result<Outcome> {
+        const SCROLL_COUNT: usize = 12;
+
+        let input = input.into();
+        let text_focused = file_explorer.prompt().is_some()
+            || file_explorer.bookmarks_popup().is_some()
+            || file_explorer.pending_overwrite().is_some();
+
+        if input == Input::Complete && !text_focused {
+            self.focused = !self.focused && !self.places.is_empty();
+            return Ok(Outcome::Nothing);
+        }
+        if !self.focused {
+            return file_explorer.handle(input);
+        }
+
+        let last = self.places.len().saturating_sub(1);
+        match input.as_navigation() {
+            Input::Up => self.selected = self.selected.checked_sub(1).unwrap_or(last),
+            Input::Down => {
+                self.selected = if self.selected >= last {
+                    0
+                } else {
+                    self.selected + 1
+                }
+            }
+            Input::Home => self.selected = 0,
+            Input::End => self.selected = last,
+            Input::PageUp => self.selected = self.selected.saturating_sub(SCROLL_COUNT),
+            Input::PageDown => self.selected = (self.selected + SCROLL_COUNT).min(last),
+            Input::Right | Input::Confirm => {
+                self.focused = false;
+                if let Some(place) = self.places.get(self.selected) {
+                    return file_explorer.go_to(place.path.clone());
+                }
+            }
+            Input::Left | Input::Cancel => self.focused = false,
+            _ => {}
+        }
+
+        Ok(Outcome::Nothing)
+    }
+}
+
+/// Returns the name of the place leading to `path`.
+fn name_of(path: &Path) -> String {
+    path.file_name().map_or_else(
+        || path.display().to_string(),
+        |name| name.to_string_lossy().into_owned(),
+    )
+}
+
+/// Parses the content of a `user-dirs.dirs` file and returns the user directories, except the home directory
+/// itself (used to disable a user directory).
+fn parse_user_dirs(content: &str, home: &Path) -> Vec<PathBuf> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let (key, value) = line.trim().split_once('=')?;
+            if !key.starts_with("XDG_") || !key.ends_with("_DIR") {
+                return None;
+            }
+
+            let value = value.strip_prefix('"')?.strip_suffix('"')?;
+            let path = match value.strip_prefix("$HOME") {
+                Some(relative) => home.join(relative.trim_start_matches('/')),
+                None => PathBuf::from(value),
+            };
+
+            (path.is_absolute() && path != home).then_some(path)
+        })
+        .collect()
+}
+
+/// Parses the content of a `/proc/<pid>/mountinfo` file and returns the mount points of the filesystems
+/// that are not virtual.
+fn parse_mountinfo(content: &str) -> Vec<PathBuf> {
+    const VIRTUAL_TYPES: &[&str] = &[
+        "autofs",
+        "binfmt_misc",
+        "bpf",
+        "cgroup",
+        "cgroup2",
+        "configfs",
+        "debugfs",
+        "devpts",
+        "devtmpfs",
+        "efivarfs",
+        "fusectl",
+        "hugetlbfs",
+        "mqueue",
+        "nsfs",
+        "proc",
+        "pstore",
+        "ramfs",
+        "rpc_pipefs",
+        "securityfs",
+        "selinuxfs",
+        "squashfs",
+        "sysfs",
+        "tmpfs",
+        "tracefs",
+    ];
+    const VIRTUAL_DIRS: &[&str] = &["/proc", "/sys", "/dev", "/run"];
+
+    let mut mounts: Vec<PathBuf> = Vec::new();
+    for line in content.lines() {
+        let Some((fields, optional)) = line.split_once(" - ") else {
+            continue;
+        };
+        let Some(mount_point) = fields.split(' ').nth(4) else {
+            continue;
+        };
+        let fs_type = optional.split(' ').next().unwrap_or_default();
+
+        let mount_point = PathBuf::from(unescape_octal(mount_point));
+        let is_virtual_dir = VIRTUAL_DIRS.iter().any(|dir| mount_point.starts_with(dir))
+            && !mount_point.starts_with("/run/media");
+        if VIRTUAL_TYPES.contains(&fs_type) || is_virtual_dir || mounts.contains(&mount_point) {
+            continue;
+        }
+
+        mounts.push(mount_point);
+    }
+
+    mounts
+}
+
+/// Replaces the octal escapes of `/proc/<pid>/mountinfo` (eg. `\040` for a space) by the escaped characters.
+fn unescape_octal(field: &str) -> String {
+    let mut bytes = Vec::with_capacity(field.len());
+    let mut rest = field.as_bytes();
+
+    while let Some((&byte, tail)) = rest.split_first() {
+        let escaped = tail
+            .get(..3)
+            .filter(|digits| {
+                byte == b'\\' && digits.iter().all(|digit| (b'0'..=b'7').contains(digit))
+            })
+            .and_then(|digits| u8::from_str_radix(std::str::from_utf8(digits).ok()?, 8).ok());
+
+        match escaped {
+            Some(escaped) => {
+                bytes.push(escaped);
+                rest = &tail[3..];
+            }
+            None => {
+                bytes.push(byte);
+                rest = tail;
+            }
+        }
+    }
+
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::fs;
+    use tempfile::TempDir;
+
+    use crate::FileExplorerBuilder;
+
+    #[test]
+    fn test_parse_system_files() {
+        let user_dirs = r#"
+# This file is written by xdg-user-dirs-update
+XDG_DESKTOP_DIR="$HOME/Desktop"
+XDG_DOWNLOAD_DIR="$HOME/Téléchargements"
+XDG_PUBLICSHARE_DIR="$HOME/"
+XDG_MUSIC_DIR="/srv/music"
+XDG_VIDEOS_DIR=relative
+"#;
+        assert_eq!(
+            parse_user_dirs(user_dirs, Path::new("/home/me")),
+            [
+                PathBuf::from("/home/me/Desktop"),
+                PathBuf::from("/home/me/Téléchargements"),
+                PathBuf::from("/srv/music"),
+            ]
+        );
+
+        let mountinfo = "\
+22 1 8:2 / / rw,relatime shared:1 - ext4 /dev/sda2 rw
+23 22 0:5 / /proc rw,nosuid shared:12 - proc proc rw
+24 22 0:21 / /tmp rw shared:2 - tmpfs tmpfs rw
+25 22 8:3 / /mnt/My\\040Disk rw,relatime shared:3 - ext4 /dev/sda3 rw
+26 22 8:17 / /run/media/me/USB rw shared:4 - vfat /dev/sdb1 rw
+27 22 0:40 / /run/user/1000/doc rw shared:5 - fuse.portal portal rw
+28 22 8:2 /home /home rw,relatime shared:1 - ext4 /dev/sda2 rw
+";
+        assert_eq!(
+            parse_mountinfo(mountinfo),
+            [
+                PathBuf::from("/"),
+                PathBuf::from("/mnt/My Disk"),
+                PathBuf::from("/run/media/me/USB"),
+                PathBuf::from("/home"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_places_drive_the_file_explorer() -> Result<()> {
+        let root = TempDir::new()?;
+        fs::create_dir(root.path().join("music"))?;
+        fs::create_dir(root.path().join("projects"))?;
+
+        let mut file_explorer = FileExplorerBuilder::build_with_working_dir(root.path())?;
+        let mut bookmarks = Bookmarks::new();
+        bookmarks.insert("work", root.path().join("projects"))?;
+        let mut places = Places::empty()
+            .with_place(Place::new(
+                "Music",
+                root.path().join("music"),
+                PlaceKind::Custom,
+            ))
+            .with_bookmarks(&bookmarks);
+
+        // Unfocused, the inputs go to the file explorer.
+        assert_eq!(
+            places.handle(Input::Down, &mut file_explorer)?,
+            Outcome::SelectionChanged
+        );
+
+        places.handle(Input::Complete, &mut file_explorer)?;
+        assert!(places.is_focused());
+        places.handle(Input::Char('j'), &mut file_explorer)?;
+        assert_eq!(
+            places.selected().map(|place| place.name.as_str()),
+            Some("work")
+        );
+        assert_eq!(file_explorer.selected_idx(), 1);
+
+        let outcome = places.handle(Input::Confirm, &mut file_explorer)?;
+        assert_eq!(
+            outcome,
+            Outcome::DirectoryChanged {
+                from: root.path().to_path_buf(),
+                to: root.path().join("projects"),
+            }
+        );
+        assert!(!places.is_focused());
+
+        bookmarks.remove("work")?;
+        places.set_bookmarks(&bookmarks);
+        assert_eq!(places.places().len(), 1);
+        assert_eq!(places.selected_idx(), 0);
+
+        Ok(())
+    }
+}
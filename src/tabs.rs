@@ -0,0 +1,267 @@
+use ratatui::widgets::WidgetRef;
+
+use crate::{FileExplorer, Input, Outcome, Result, widget::TabsRenderer};
+
+/// Several [`FileExplorer`]s in tabs, each one with its own working directory, selection and filters.
+/// One tab is shown at a time, below a bar listing the tabs by the name of their working directory.
+///
+/// The tabs share the files of the [`Bookmarks`](crate::Bookmarks) and of the [`Frecency`](crate::Frecency)
+/// database: the bookmarks and the visits of every tab are saved to them without overwriting the others.
+///
+/// # Examples
+///
+/// ```no_run
+/// use ratatui::widgets::FrameExt;
+/// use ratatui_explorer::{ExplorerTabs, FileExplorer, Input};
+///
+/// let mut tabs = ExplorerTabs::new(FileExplorer::new().unwrap());
+///
+/// # fn draw(f: &mut ratatui::Frame, tabs: &ExplorerTabs) {
+/// f.render_widget_ref(tabs.widget(), f.area());
+/// # }
+/// # fn get_event() -> Input { Input::None }
+///
+/// // `NewTab`, `CloseTab`, `NextTab`, `PreviousTab` and `DuplicateTab` manage the tabs.
+/// tabs.handle(get_event()).unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ExplorerTabs {
+    /// The file explorer new tabs are copied from.
+    template: FileExplorer,
+    tabs: Vec<FileExplorer>,
+    selected: usize,
+}
+
+impl ExplorerTabs {
+    /// Creates a single tab showing `file_explorer`.
+    ///
+    /// The new tabs are copies of `file_explorer` as it is now: they start in its current working directory,
+    /// with its theme, filters and hooks.
+    #[must_use]
+    pub fn new(file_explorer: FileExplorer) -> Self {
+        Self {
+            template: file_explorer.clone(),
+            tabs: vec![file_explorer],
+            selected: 0,
+        }
+    }
+
+    /// Returns the file explorers of the tabs.
+    #[inline]
+    #[must_use]
+    pub fn tabs(&self) -> &[FileExplorer] {
+        &self.tabs
+    }
+
+    /// Returns the index of the shown tab.
+    #[inline]
+    #[must_use]
+    pub const fn selected_idx(&self) -> usize {
+        self.selected
+    }
+
+    /// Shows the tab at `selected`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `selected` is out of bounds.
+    #[inline]
+    pub fn set_selected_idx(&mut self, selected: usize) {
+        assert!(selected < self.tabs.len());
+        self.selected = selected;
+    }
+
+    /// Returns the file explorer of the shown tab.
+    #[inline]
+    #[must_use]
+    pub fn current(&self) -> &FileExplorer {
+        &self.tabs[self.selected]
+    }
+
+    /// Returns the file explorer of the shown tab, mutably.
+    #[inline]
+    pub fn current_mut(&mut self) -> &mut FileExplorer {
+        &mut self.tabs[self.selected]
+    }
+
+    /// Opens a new tab showing `file_explorer` after the shown tab, and shows it.
+    pub fn push(&mut self, file_explorer: FileExplorer) {
+        self.selected += 1;
+        self.tabs.insert(self.selected, file_explorer);
+    }
+
+    /// Opens a new tab after the shown tab, and shows it. It is a copy of the file explorer given to
+    /// [`new`](ExplorerTabs::new).
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the working directory of the new tab can not be listed.
+    pub fn new_tab(&mut self) -> Result<()> {
+        let mut file_explorer = self.template.clone();
+        file_explorer.refresh()?;
+        self.push(file_explorer);
+
+        Ok(())
+    }
+
+    /// Opens a copy of the shown tab after it, and shows it.
+    pub fn duplicate_tab(&mut self) {
+        let file_explorer = self.current().clone();
+        self.push(file_explorer);
+    }
+
+    /// Closes the shown tab and shows the previous one. Returns the file explorer of the closed tab, or `None`
+    /// if it is the last tab, which can not be closed.
+    pub fn close_tab(&mut self) -> Option<FileExplorer> {
+        if self.tabs.len() == 1 {
+            return None;
+        }
+
+        let closed = self.tabs.remove(self.selected);
+        self.selected = self.selected.saturating_sub(1);
+        Some(closed)
+    }
+
+    /// Shows the next tab, or the first one after the last one.
+    #[inline]
+    pub fn next_tab(&mut self) {
+        self.selected = (self.selected + 1) % self.tabs.len();
+    }
+
+    /// Shows the previous tab, or the last one before the first one.
+    #[inline]
+    pub fn previous_tab(&mut self) {
+        self.selected = self.selected.checked_sub(1).unwrap_or(self.tabs.len() - 1);
+    }
+
+    /// Returns a widget rendering the tab bar and the shown tab below it, styled with its [`Theme`](crate::Theme).
+    #[inline]
+    #[must_use]
+    pub const fn widget(&self) -> impl WidgetRef + '_ {
+        TabsRenderer(self)
+    }
+
+    /// Handles the input from the user.
+    ///
+    /// The inputs are interpreted as follows, the other ones being handled by the file explorer of the shown tab
+    /// (see [`FileExplorer::handle`]):
+    /// - `NewTab`: Open a new tab (see [`new_tab`](ExplorerTabs::new_tab)).
+    /// - `CloseTab`: Close the shown tab, unless it is the last one.
+    /// - `NextTab`: Show the next tab.
+    /// - `PreviousTab`: Show the previous tab.
+    /// - `DuplicateTab`: Open a copy of the shown tab.
+    ///
+    /// The outcome of the inputs managing the tabs is [`Outcome::Nothing`].
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the file explorer of the shown tab returns one, or if a new tab can not be opened.
+    pub fn handle<I: Into<Input>>(&mut self, input: I) -> Result<Outcome> {
+        match input.into() {
+            Input::NewTab => self.new_tab()?,
+            Input::CloseTab => {
+                self.close_tab();
+            }
+            Input::NextTab => self.next_tab(),
+            Input::PreviousTab => self.previous_tab(),
+            Input::DuplicateTab => self.duplicate_tab(),
+            input => return self.current_mut().handle(input),
+        }
+
+        Ok(Outcome::Nothing)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::fs;
+    use tempfile::TempDir;
+
+    use crate::{Bookmarks, FileExplorerBuilder, Filter, Frecency};
+
+    #[test]
+    fn test_tabs_keep_their_own_state() -> Result<()> {
+        let root = TempDir::new()?;
+        fs::create_dir(root.path().join("music"))?;
+        fs::write(root.path().join("notes.txt"), "")?;
+        fs::write(root.path().join("song.mp3"), "")?;
+
+        let mut tabs = ExplorerTabs::new(FileExplorerBuilder::build_with_working_dir(root.path())?);
+        tabs.handle(Input::Down)?;
+        tabs.handle(Input::Right)?;
+        assert_eq!(tabs.current().cwd(), &root.path().join("music"));
+
+        // A new tab starts where the first one started, a duplicate where the shown one is.
+        tabs.handle(Input::NewTab)?;
+        assert_eq!(tabs.selected_idx(), 1);
+        assert_eq!(tabs.current().cwd(), root.path());
+        tabs.current_mut()
+            .add_filter("audio", Filter::extension(["mp3"]))?;
+        assert_eq!(tabs.current().files().len(), 3);
+
+        tabs.handle(Input::DuplicateTab)?;
+        assert_eq!(tabs.tabs().len(), 3);
+        assert_eq!(tabs.current().files().len(), 3);
+
+        tabs.handle(Input::NextTab)?;
+        assert_eq!(tabs.selected_idx(), 0);
+        assert_eq!(tabs.current().cwd(), &root.path().join("music"));
+        tabs.handle(Input::PreviousTab)?;
+        assert_eq!(tabs.selected_idx(), 2);
+
+        tabs.handle(Input::CloseTab)?;
+        tabs.handle(Input::CloseTab)?;
+        tabs.handle(Input::CloseTab)?;
+        assert_eq!(tabs.tabs().len(), 1);
+        assert_eq!(tabs.current().cwd(), &root.path().join("music"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_tabs_share_bookmarks_and_visits() -> Result<()> {
+        let root = TempDir::new()?;
+        let bookmarks_path = root.path().join("data/bookmarks");
+        let frecency_path = root.path().join("data/frecency");
+        fs::create_dir(root.path().join("music"))?;
+        fs::create_dir(root.path().join("photos"))?;
+
+        let mut tabs = ExplorerTabs::new(
+            FileExplorerBuilder::default()
+                .working_dir(root.path())
+                .bookmarks(Bookmarks::open(&bookmarks_path)?)
+                .frecency(Frecency::open(&frecency_path)?)
+                .build()?,
+        );
+        tabs.handle(Input::DuplicateTab)?;
+        tabs.handle(Input::NewTab)?;
+
+        tabs.current_mut().add_bookmark("r")?;
+        tabs.current_mut().set_cwd(root.path().join("music"))?;
+        tabs.handle(Input::NextTab)?;
+        tabs.current_mut().add_bookmark("m")?;
+        tabs.current_mut().set_cwd(root.path().join("photos"))?;
+        tabs.handle(Input::NextTab)?;
+        tabs.current_mut().set_cwd(root.path().join("photos"))?;
+
+        // The bookmarks popup shows the bookmarks added by the other tabs.
+        tabs.handle(Input::Bookmarks)?;
+        assert_eq!(tabs.current().bookmarks().len(), 2);
+        assert_eq!(Bookmarks::open(&bookmarks_path)?.len(), 2);
+
+        drop(tabs);
+        let frecency = Frecency::open(&frecency_path)?;
+        assert_eq!(
+            frecency.query("photos"),
+            [root.path().join("photos").as_path()]
+        );
+        assert_eq!(
+            frecency.query("music"),
+            [root.path().join("music").as_path()]
+        );
+
+        Ok(())
+    }
+}
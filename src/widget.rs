@@ -2,15 +2,47 @@ use std::sync::Arc;
 
 use ratatui::{
     buffer::Buffer,
-    layout::Rect,
-    style::{Color, Style},
+    layout::{Constraint, Flex, Layout, Rect},
+    style::{Color, Modifier, Style},
     text::{Line, Span, Text},
-    widgets::{Block, Borders, HighlightSpacing, List, ListState, WidgetRef},
+    widgets::{Block, Borders, Clear, HighlightSpacing, List, ListState, Widget, WidgetRef},
 };
 
-use crate::{File, FileExplorer};
+use crate::{DialogMode, File, FileExplorer, Filter, GitStatus, PromptKind};
+
+mod dual_pane;
+mod icons;
+mod ls_colors;
+mod places;
+mod tabs;
+
+pub(crate) use dual_pane::DualPaneRenderer;
+pub use icons::{Icon, Icons};
+pub(crate) use places::PlacesRenderer;
+pub(crate) use tabs::TabsRenderer;
 
 type LineFactory = Arc<dyn Fn(&FileExplorer) -> Line<'_> + Send + Sync>;
+type RowRenderer = Arc<dyn Fn(&File, RowContext) -> Text<'_> + Send + Sync>;
+
+/// The state of a row of the listing, passed to the [row renderer](Theme::with_row_renderer) of the theme.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct RowContext {
+    /// The row is the selected entry.
+    pub selected: bool,
+    /// The entry is [marked](FileExplorer::toggle_mark).
+    pub marked: bool,
+    /// The entry is under the mouse cursor (see [`FileExplorer::set_hovered`]).
+    pub hovered: bool,
+    /// The name of the entry matches the [search](FileExplorer::search).
+    pub search_match: bool,
+    /// The size of the entry in bytes, if the [disk-usage mode](FileExplorer::set_disk_usage) is enabled and
+    /// it is known.
+    pub size: Option<u64>,
+    /// The total size of the listed entries in bytes, if the [disk-usage mode](FileExplorer::set_disk_usage)
+    /// is enabled.
+    pub total_size: Option<u64>,
+}
 
 pub struct Renderer<'a>(pub(crate) &'a FileExplorer);
 
@@ -21,23 +53,40 @@ impl WidgetRef for Renderer<'_> {
     {
         let mut state = ListState::default().with_selected(Some(self.0.selected_idx()));
 
-        let highlight_style = if self.0.current().is_dir {
+        let highlight_style = if self.0.try_current().is_some_and(|file| file.is_dir) {
             self.0.theme().highlight_dir_style
         } else {
             self.0.theme().highlight_item_style
         };
 
-        let mut list = List::new(self.0.files().iter().map(|file| file.text(self.0.theme())))
-            .style(self.0.theme().style)
-            .highlight_spacing(self.0.theme().highlight_spacing.clone())
-            .highlight_style(highlight_style)
-            .scroll_padding(self.0.theme().scroll_padding);
+        let git_column = self.0.files().iter().any(|file| file.git_status.is_some());
+        let total_size = self.0.total_size();
+
+        let mut list = List::new(self.0.files().iter().enumerate().map(|(idx, file)| {
+            let context = RowContext {
+                selected: idx == self.0.selected_idx(),
+                marked: self.0.is_marked(&file.path),
+                hovered: self.0.hovered() == Some(idx),
+                search_match: self.0.is_search_match(file),
+                size: self.0.size(&file.path),
+                total_size,
+            };
+
+            match self.0.theme().row_renderer.as_deref() {
+                Some(row_renderer) => row_renderer(file, context),
+                None => file.text(self.0.theme(), context, git_column),
+            }
+        }))
+        .style(self.0.theme().style)
+        .highlight_spacing(self.0.theme().highlight_spacing.clone())
+        .highlight_style(highlight_style)
+        .scroll_padding(self.0.theme().scroll_padding);
 
         if let Some(symbol) = self.0.theme().highlight_symbol.as_deref() {
             list = list.highlight_symbol(symbol);
         }
 
-        if let Some(block) = self.0.theme().block.as_ref() {
+        let area = if let Some(block) = self.0.theme().block.as_ref() {
             let mut block = block.clone();
 
             for title_top in self.0.theme().title_top(self.0) {
@@ -47,25 +96,226 @@ impl WidgetRef for Renderer<'_> {
                 block = block.title_bottom(title_bottom);
             }
 
-            list = list.block(block);
-        }
+            let inner = block.inner(area);
+            Widget::render(&block, area, buf);
+            inner
+        } else {
+            area
+        };
+
+        let prompt_lines = self.prompt_lines();
+        let [list_area, prompt_area] = Layout::vertical([
+            Constraint::Fill(1),
+            Constraint::Length(prompt_lines.len() as u16),
+        ])
+        .areas(area);
+        Widget::render(Text::from(prompt_lines), prompt_area, buf);
+        let area = list_area;
 
         ratatui::widgets::StatefulWidget::render(&list, area, buf, &mut state);
+
+        if let Some(selected) = self.0.bookmarks_popup() {
+            self.render_bookmarks_popup(selected, area, buf);
+        }
     }
 }
 
-impl File {
-    /// Returns the text with the appropriate style to be displayed for the file.
-    fn text(&self, theme: &Theme) -> Text<'_> {
-        let style = if self.is_dir {
-            *theme.dir_style()
+impl Renderer<'_> {
+    /// Renders the bookmarks popup centered over `area`.
+    fn render_bookmarks_popup(&self, selected: usize, area: Rect, buf: &mut Buffer) {
+        let theme = self.0.theme();
+        let bookmarks = self.0.bookmarks();
+
+        let name_width = bookmarks
+            .iter()
+            .map(|(name, _)| name.len())
+            .max()
+            .unwrap_or(0);
+        let items = bookmarks.iter().map(|(name, path)| {
+            Line::from(vec![
+                Span::styled(format!("{name:<name_width$}  "), theme.prompt_style),
+                Span::styled(path.display().to_string(), theme.dir_style),
+            ])
+        });
+
+        let height = (bookmarks.len() as u16 + 2).max(3);
+        let [popup_area] = Layout::vertical([Constraint::Length(height)])
+            .flex(Flex::Center)
+            .areas(area);
+        let [popup_area] = Layout::horizontal([Constraint::Percentage(80)])
+            .flex(Flex::Center)
+            .areas(popup_area);
+
+        let list = List::new(items)
+            .block(Block::bordered().title(" Bookmarks "))
+            .style(theme.style)
+            .highlight_style(theme.highlight_dir_style);
+        let mut state = ListState::default().with_selected(Some(selected));
+
+        Widget::render(Clear, popup_area, buf);
+        ratatui::widgets::StatefulWidget::render(&list, popup_area, buf, &mut state);
+    }
+
+    /// Returns the lines to display below the listing.
+    fn prompt_lines(&self) -> Vec<Line<'_>> {
+        let theme = self.0.theme();
+
+        let message = self
+            .0
+            .message()
+            .map(|message| Line::from(message).style(theme.error_style));
+
+        if let Some(prompt) = self.0.prompt() {
+            let mut lines = Vec::with_capacity(4);
+            lines.extend(message);
+
+            if !prompt.candidates().is_empty() {
+                lines.push(Line::from(prompt.candidates().join("  ")).style(theme.style));
+            }
+            if let Some(error) = prompt.error() {
+                lines.push(Line::from(error).style(theme.error_style));
+            }
+
+            let label = match prompt.kind() {
+                PromptKind::GoTo => "Go to: ",
+                PromptKind::AddBookmark => "Bookmark as: ",
+                PromptKind::Jump => "Jump to: ",
+                PromptKind::Search => "Search: ",
+                PromptKind::Find => "Find: ",
+                PromptKind::Grep => "Grep: ",
+            };
+            lines.push(
+                Line::from(vec![Span::raw(label), Span::raw(prompt.input())])
+                    .style(theme.prompt_style),
+            );
+
+            return lines;
+        }
+
+        let dialog_line = if let Some(path) = self.0.pending_overwrite() {
+            let name = path.file_name().unwrap_or_default().to_string_lossy();
+            Some(Line::from(format!("Overwrite {name}? (y/n)")).style(theme.prompt_style))
+        } else if let DialogMode::SaveAs { .. } = self.0.dialog_mode() {
+            Some(
+                Line::from(vec![Span::raw("Save as: "), Span::raw(self.0.filename())])
+                    .style(theme.prompt_style),
+            )
         } else {
-            *theme.item_style()
+            None
         };
-        Span::styled(&self.name, style).into()
+
+        let find_line = self.0.is_finding().then(|| {
+            let results = self.0.files().len() - 1;
+            let status = if self.0.is_find_running() {
+                " (searching...)"
+            } else {
+                ""
+            };
+            Line::from(format!("Found {results} files{status}")).style(theme.prompt_style)
+        });
+
+        let disk_usage_line = self
+            .0
+            .is_disk_usage_running()
+            .then(|| Line::from("Computing sizes...").style(theme.prompt_style));
+
+        message
+            .into_iter()
+            .chain(find_line)
+            .chain(disk_usage_line)
+            .chain(dialog_line)
+            .collect()
+    }
+}
+
+impl File {
+    /// Returns the text with the appropriate style to be displayed for the file,
+    /// preceded by its size in the disk-usage mode, by its git status marker if `git_column` is `true`
+    /// and by its icon if the theme has icons.
+    fn text(&self, theme: &Theme, context: RowContext, git_column: bool) -> Text<'_> {
+        const BAR_WIDTH: usize = 10;
+
+        let mut spans = Vec::with_capacity(4);
+
+        if let Some(total_size) = context.total_size {
+            spans.push(match context.size {
+                Some(size) => {
+                    let share = if total_size == 0 {
+                        0.0
+                    } else {
+                        size as f64 / total_size as f64
+                    };
+                    let filled = (share * BAR_WIDTH as f64).round() as usize;
+                    Span::raw(format!(
+                        "{:>10} {:>5.1}% [{:<BAR_WIDTH$}] ",
+                        human_size(size),
+                        share * 100.0,
+                        "#".repeat(filled),
+                    ))
+                }
+                None => Span::raw(" ".repeat(BAR_WIDTH + 21)),
+            });
+        }
+
+        if git_column {
+            spans.push(match self.git_status {
+                Some(status) => {
+                    Span::styled(format!("{} ", status.marker()), *theme.git_style(status))
+                }
+                None => Span::raw("  "),
+            });
+        }
+        if let Some(icons) = theme.icons() {
+            spans.push(match icons.icon(self) {
+                Some(icon) => Span::styled(format!("{} ", icon.glyph), icon.color),
+                None => Span::raw("  "),
+            });
+        }
+        let mut style = theme.file_style(self);
+        if context.marked {
+            style = style.patch(theme.marked_style);
+        }
+        if context.search_match {
+            style = style.patch(theme.search_style);
+        }
+        spans.push(Span::styled(&self.name, style));
+
+        if theme.link_targets
+            && let Some(target) = &self.link_target
+        {
+            let target_style = if self.is_broken_symlink() {
+                theme.error_style
+            } else if self.is_dir {
+                theme.dir_style
+            } else {
+                theme.item_style
+            };
+            spans.push(Span::raw(" -> "));
+            spans.push(Span::styled(target.display().to_string(), target_style));
+        }
+
+        Line::from(spans).into()
     }
 }
 
+/// Formats `bytes` with a binary unit (eg. `1.5 KiB`).
+fn human_size(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["KiB", "MiB", "GiB", "TiB", "PiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{size:.1} {}", UNITS[unit])
+}
+
 /// The theme of the file explorer.
 ///
 /// This struct is used to customize the look of the file explorer.
@@ -81,6 +331,8 @@ pub struct Theme {
     title_top: Vec<LineFactory>,
     #[educe(Debug(ignore), PartialEq(ignore), Hash(ignore))]
     title_bottom: Vec<LineFactory>,
+    #[educe(Debug(ignore), PartialEq(ignore), Hash(ignore))]
+    row_renderer: Option<RowRenderer>,
     style: Style,
     item_style: Style,
     dir_style: Style,
@@ -89,6 +341,15 @@ pub struct Theme {
     highlight_dir_style: Style,
     highlight_symbol: Option<String>,
     scroll_padding: usize,
+    prompt_style: Style,
+    error_style: Style,
+    marked_style: Style,
+    search_style: Style,
+    link_targets: bool,
+    git_styles: [Style; 5],
+    #[educe(PartialEq(ignore), Hash(ignore))]
+    style_rules: Vec<(Filter, Style)>,
+    icons: Option<Icons>,
 }
 
 impl Theme {
@@ -107,6 +368,7 @@ impl Theme {
             block: None,
             title_top: Vec::new(),
             title_bottom: Vec::new(),
+            row_renderer: None,
             style: Style::new(),
             item_style: Style::new(),
             dir_style: Style::new(),
@@ -115,6 +377,14 @@ impl Theme {
             highlight_dir_style: Style::new(),
             highlight_symbol: None,
             scroll_padding: 0,
+            prompt_style: Style::new(),
+            error_style: Style::new(),
+            marked_style: Style::new(),
+            search_style: Style::new(),
+            link_targets: false,
+            git_styles: [Style::new(); 5],
+            style_rules: Vec::new(),
+            icons: None,
         }
     }
 
@@ -236,6 +506,48 @@ impl Theme {
         self
     }
 
+    /// Add a rule styling the files matched by `filter` with `style`, taking precedence over
+    /// [`with_item_style`](Theme::with_item_style) and [`with_dir_style`](Theme::with_dir_style).
+    ///
+    /// Rules are tried in the order they were added, the first matching rule is used.
+    /// Unlike when filtering, directories are also matched. See [`Filter`] for the available matchers
+    /// (extension, glob, [kind](crate::FileKind), predicate...).
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ratatui::prelude::*;
+    /// # use ratatui_explorer::{FileKind, Filter, Theme};
+    /// let theme = Theme::default()
+    ///     .with_style_rule(Filter::file_type(FileKind::BrokenSymlink), Style::default().fg(Color::Red))
+    ///     .with_style_rule(Filter::extension(["rs", "toml"]), Style::default().fg(Color::LightRed))
+    ///     .with_style_rule(Filter::new(|file| file.name.starts_with("README")), Modifier::BOLD);
+    /// ```
+    #[inline]
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_style_rule<S: Into<Style>>(mut self, filter: Filter, style: S) -> Self {
+        self.style_rules.push((filter, style.into()));
+        self
+    }
+
+    /// Set the icons displayed before the names of the files. By default, no icon is displayed.
+    ///
+    /// Use [`Icons::nerd_font`] if the terminal uses a [Nerd Font](https://www.nerdfonts.com/),
+    /// or [`Icons::ascii`] otherwise. Both can be extended or overridden with the methods of [`Icons`].
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ratatui::prelude::*;
+    /// # use ratatui_explorer::{Icon, Icons, Theme};
+    /// let theme = Theme::default()
+    ///     .with_icons(Icons::nerd_font().with_name("justfile", Icon::new("\u{e779}", Color::Gray)));
+    /// ```
+    #[inline]
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_icons(mut self, icons: Icons) -> Self {
+        self.icons = Some(icons);
+        self
+    }
+
     /// Set the style of all highlighted non directories items. To set the style of the highlighted directories, use [`with_highlight_dir_style`](Theme::with_highlight_dir_style).
     ///
     /// Behind the scene, it use the [`List::highlight_style`](https://docs.rs/ratatui/latest/ratatui/widgets/struct.List.html#method.highlight_style) method.
@@ -325,6 +637,99 @@ impl Theme {
         self
     }
 
+    /// Set the style of the input line shown below the listing (eg. the filename of the
+    /// [`SaveAs`](crate::DialogMode::SaveAs) dialog).
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ratatui::prelude::*;
+    /// # use ratatui_explorer::Theme;
+    /// let theme = Theme::default().with_prompt_style(Style::default().fg(Color::Yellow));
+    /// ```
+    #[inline]
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_prompt_style<S: Into<Style>>(mut self, prompt_style: S) -> Self {
+        self.prompt_style = prompt_style.into();
+        self
+    }
+
+    /// Set the style of the error line shown below the listing (eg. when the path typed in the
+    /// [`GoTo`](crate::PromptKind::GoTo) prompt does not exist).
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ratatui::prelude::*;
+    /// # use ratatui_explorer::Theme;
+    /// let theme = Theme::default().with_error_style(Style::default().fg(Color::Red));
+    /// ```
+    #[inline]
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_error_style<S: Into<Style>>(mut self, error_style: S) -> Self {
+        self.error_style = error_style.into();
+        self
+    }
+
+    /// Set whether the targets of the symbolic links are shown after their names, like `ls -l` does
+    /// (`name -> target`). The targets of the broken links use the [error style](Theme::with_error_style).
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ratatui_explorer::Theme;
+    /// let theme = Theme::default().with_link_targets(true);
+    /// ```
+    #[inline]
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_link_targets(mut self, link_targets: bool) -> Self {
+        self.link_targets = link_targets;
+        self
+    }
+
+    /// Set the style patched onto the names of the [marked](FileExplorer::toggle_mark) entries.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ratatui::prelude::*;
+    /// # use ratatui_explorer::Theme;
+    /// let theme = Theme::default().with_marked_style(Style::default().fg(Color::Yellow));
+    /// ```
+    #[inline]
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_marked_style<S: Into<Style>>(mut self, marked_style: S) -> Self {
+        self.marked_style = marked_style.into();
+        self
+    }
+
+    /// Set the style patched onto the names of the entries matching the [search](crate::PromptKind::Search).
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ratatui::prelude::*;
+    /// # use ratatui_explorer::Theme;
+    /// let theme = Theme::default().with_search_style(Style::default().bg(Color::Blue));
+    /// ```
+    #[inline]
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_search_style<S: Into<Style>>(mut self, search_style: S) -> Self {
+        self.search_style = search_style.into();
+        self
+    }
+
+    /// Set the style of the marker shown before the files with the given git `status`
+    /// (requires the `git` feature).
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ratatui::prelude::*;
+    /// # use ratatui_explorer::{GitStatus, Theme};
+    /// let theme = Theme::default().with_git_style(GitStatus::Modified, Style::default().fg(Color::Blue));
+    /// ```
+    #[inline]
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_git_style<S: Into<Style>>(mut self, status: GitStatus, style: S) -> Self {
+        self.git_styles[status as usize] = style.into();
+        self
+    }
+
     /// Add a top title factory to the theme.
     ///
     /// `title_top` is a function that take a reference to the current [`FileExplorer`] and returns
@@ -389,6 +794,38 @@ impl Theme {
         self
     }
 
+    /// Set the function rendering each row of the listing, replacing the default rendering (the git status
+    /// marker, the icon and the styled name of the file).
+    ///
+    /// The function receives the file and the [state of its row](RowContext), and can return multi-span
+    /// and multi-line texts. The highlight style is still applied over the selected row.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ratatui::prelude::*;
+    /// # use ratatui_explorer::{File, RowContext, Theme};
+    /// let theme = Theme::default().with_row_renderer(|file: &File, context: RowContext| {
+    ///     let mark = if context.marked { "* " } else { "  " };
+    ///     let size = file.path.metadata().map(|metadata| metadata.len()).unwrap_or_default();
+    ///
+    ///     Line::from(vec![
+    ///         Span::raw(mark),
+    ///         Span::styled(format!("{size:>8}B  "), Style::default().fg(Color::DarkGray)),
+    ///         Span::raw(&file.name),
+    ///     ])
+    ///     .into()
+    /// });
+    /// ```
+    #[inline]
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_row_renderer(
+        mut self,
+        row_renderer: impl Fn(&File, RowContext) -> Text<'_> + 'static + Send + Sync,
+    ) -> Self {
+        self.row_renderer = Some(Arc::new(row_renderer));
+        self
+    }
+
     /// Returns the wrapping block (if it exist) of the file explorer of the theme.
     #[inline]
     #[must_use]
@@ -431,6 +868,70 @@ impl Theme {
         &self.highlight_dir_style
     }
 
+    /// Returns the style of the input line shown below the listing of the theme.
+    #[inline]
+    #[must_use]
+    pub const fn prompt_style(&self) -> &Style {
+        &self.prompt_style
+    }
+
+    /// Returns the style of the error line shown below the listing of the theme.
+    #[inline]
+    #[must_use]
+    pub const fn error_style(&self) -> &Style {
+        &self.error_style
+    }
+
+    /// Returns whether the targets of the symbolic links are shown after their names.
+    #[inline]
+    #[must_use]
+    pub const fn link_targets(&self) -> bool {
+        self.link_targets
+    }
+
+    /// Returns the style patched onto the names of the marked entries of the theme.
+    #[inline]
+    #[must_use]
+    pub const fn marked_style(&self) -> &Style {
+        &self.marked_style
+    }
+
+    /// Returns the style patched onto the names of the entries matching the search of the theme.
+    #[inline]
+    #[must_use]
+    pub const fn search_style(&self) -> &Style {
+        &self.search_style
+    }
+
+    /// Returns the style of `file`: the style of the first matching [style rule](Theme::with_style_rule),
+    /// or the style of the directories or of the items.
+    #[must_use]
+    pub fn file_style(&self, file: &File) -> Style {
+        self.style_rules
+            .iter()
+            .find(|(filter, _)| filter.matches(file))
+            .map(|(_, style)| *style)
+            .unwrap_or(if file.is_dir {
+                self.dir_style
+            } else {
+                self.item_style
+            })
+    }
+
+    /// Returns the style of the marker shown before the files with the given git `status`.
+    #[inline]
+    #[must_use]
+    pub const fn git_style(&self, status: GitStatus) -> &Style {
+        &self.git_styles[status as usize]
+    }
+
+    /// Returns the icons displayed before the names of the files, if any.
+    #[inline]
+    #[must_use]
+    pub const fn icons(&self) -> Option<&Icons> {
+        self.icons.as_ref()
+    }
+
     /// Returns the symbol used to highlight the selected item of the theme.
     #[inline]
     #[must_use]
@@ -489,6 +990,7 @@ impl Default for Theme {
             block: Some(Block::default().borders(Borders::ALL)),
             title_top: Vec::new(),
             title_bottom: Vec::new(),
+            row_renderer: None,
             style: Style::default(),
             item_style: Style::default().fg(Color::White),
             dir_style: Style::default().fg(Color::LightBlue),
@@ -497,6 +999,22 @@ impl Default for Theme {
             highlight_dir_style: Style::default().fg(Color::LightBlue).bg(Color::DarkGray),
             highlight_symbol: None,
             scroll_padding: 0,
+            prompt_style: Style::default().fg(Color::Yellow),
+            error_style: Style::default().fg(Color::Red),
+            marked_style: Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+            search_style: Style::default().add_modifier(Modifier::UNDERLINED),
+            link_targets: false,
+            git_styles: [
+                Style::default().fg(Color::DarkGray),
+                Style::default().fg(Color::LightRed),
+                Style::default().fg(Color::Green),
+                Style::default().fg(Color::Yellow),
+                Style::default().fg(Color::Magenta),
+            ],
+            style_rules: Vec::new(),
+            icons: None,
         }
     }
 }
@@ -0,0 +1,35 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Layout, Rect},
+    text::Line,
+    widgets::{Widget, WidgetRef},
+};
+
+use crate::{DualPane, Pane};
+
+pub struct DualPaneRenderer<'a>(pub(crate) &'a DualPane);
+
+impl WidgetRef for DualPaneRenderer<'_> {
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        let areas: [Rect; 2] =
+            Layout::horizontal([Constraint::Fill(1), Constraint::Fill(1)]).areas(area);
+
+        for (pane, area) in [Pane::Left, Pane::Right].into_iter().zip(areas) {
+            let file_explorer = self.0.pane(pane);
+            let theme = file_explorer.theme();
+
+            let [header_area, listing_area] =
+                Layout::vertical([Constraint::Length(1), Constraint::Fill(1)]).areas(area);
+
+            let header_style = if pane == self.0.focused() {
+                theme.highlight_dir_style
+            } else {
+                theme.style
+            };
+            let header = Line::from(format!(" {} ", file_explorer.cwd().display()));
+            Widget::render(header.style(header_style), header_area, buf);
+
+            file_explorer.widget().render_ref(listing_area, buf);
+        }
+    }
+}
@@ -0,0 +1,278 @@
+use std::collections::BTreeMap;
+
+use ratatui::style::Color;
+
+use crate::{File, FileKind};
+
+/// An icon displayed before the name of a file (see [`Icons`]).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Icon {
+    /// The glyph of the icon (eg. a [Nerd Font](https://www.nerdfonts.com/) glyph).
+    pub glyph: String,
+    /// The color of the glyph.
+    pub color: Color,
+}
+
+impl Icon {
+    /// Creates a new icon.
+    pub fn new<S: Into<String>>(glyph: S, color: Color) -> Self {
+        Self {
+            glyph: glyph.into(),
+            color,
+        }
+    }
+}
+
+/// The icons displayed before the names of the files, set with [`Theme::with_icons`](crate::Theme::with_icons).
+///
+/// The icon of a file is looked up by its file name (eg. `Cargo.toml`), then by its extension for
+/// regular files, then by its [`FileKind`].
+///
+/// # Examples
+///
+/// ```no_run
+/// # use ratatui::style::Color;
+/// # use ratatui_explorer::{Icon, Icons, Theme};
+/// let icons = Icons::nerd_font()
+///     .with_extension("nix", Icon::new("\u{f313}", Color::LightBlue))
+///     .with_name("flake.lock", Icon::new("\u{f023}", Color::DarkGray));
+///
+/// let theme = Theme::default().with_icons(icons);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Icons {
+    names: BTreeMap<String, Icon>,
+    extensions: BTreeMap<String, Icon>,
+    kinds: Vec<(FileKind, Icon)>,
+}
+
+impl Icons {
+    /// Creates an empty set of icons.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            names: BTreeMap::new(),
+            extensions: BTreeMap::new(),
+            kinds: Vec::new(),
+        }
+    }
+
+    /// Creates a set of icons for the terminals using a [Nerd Font](https://www.nerdfonts.com/).
+    #[must_use]
+    pub fn nerd_font() -> Self {
+        const NAMES: &[(&str, &str, Color)] = &[
+            (".git", "\u{e5fb}", Color::LightRed),
+            (".gitattributes", "\u{f1d3}", Color::LightRed),
+            (".gitignore", "\u{f1d3}", Color::LightRed),
+            (".gitmodules", "\u{f1d3}", Color::LightRed),
+            ("Cargo.lock", "\u{e7a8}", Color::DarkGray),
+            ("Cargo.toml", "\u{e7a8}", Color::LightRed),
+            ("Dockerfile", "\u{f308}", Color::Blue),
+            ("LICENSE", "\u{f0e3}", Color::Yellow),
+            ("Makefile", "\u{e779}", Color::Gray),
+            ("node_modules", "\u{e5fa}", Color::Red),
+            ("package.json", "\u{e71e}", Color::Red),
+        ];
+        const EXTENSIONS: &[(&str, &str, Color)] = &[
+            ("c", "\u{e61e}", Color::Blue),
+            ("conf", "\u{e615}", Color::Gray),
+            ("cpp", "\u{e61d}", Color::Blue),
+            ("css", "\u{e749}", Color::Blue),
+            ("flac", "\u{f1c7}", Color::Cyan),
+            ("gif", "\u{f1c5}", Color::Magenta),
+            ("go", "\u{e626}", Color::Cyan),
+            ("gz", "\u{f1c6}", Color::Red),
+            ("h", "\u{e61e}", Color::Magenta),
+            ("html", "\u{e736}", Color::LightRed),
+            ("ini", "\u{e615}", Color::Gray),
+            ("java", "\u{e738}", Color::Red),
+            ("jpeg", "\u{f1c5}", Color::Magenta),
+            ("jpg", "\u{f1c5}", Color::Magenta),
+            ("js", "\u{e74e}", Color::Yellow),
+            ("json", "\u{e60b}", Color::Yellow),
+            ("lock", "\u{f023}", Color::DarkGray),
+            ("lua", "\u{e620}", Color::Blue),
+            ("md", "\u{e73e}", Color::White),
+            ("mkv", "\u{f1c8}", Color::Magenta),
+            ("mp3", "\u{f1c7}", Color::Cyan),
+            ("mp4", "\u{f1c8}", Color::Magenta),
+            ("pdf", "\u{f1c1}", Color::Red),
+            ("php", "\u{e73d}", Color::Magenta),
+            ("png", "\u{f1c5}", Color::Magenta),
+            ("py", "\u{e73c}", Color::Yellow),
+            ("rb", "\u{e739}", Color::Red),
+            ("rs", "\u{e7a8}", Color::LightRed),
+            ("sh", "\u{f489}", Color::Green),
+            ("svg", "\u{f1c5}", Color::Yellow),
+            ("tar", "\u{f1c6}", Color::Red),
+            ("toml", "\u{e615}", Color::Gray),
+            ("ts", "\u{e628}", Color::Blue),
+            ("txt", "\u{f15c}", Color::White),
+            ("vim", "\u{e62b}", Color::Green),
+            ("wav", "\u{f1c7}", Color::Cyan),
+            ("yaml", "\u{e615}", Color::Gray),
+            ("yml", "\u{e615}", Color::Gray),
+            ("zip", "\u{f1c6}", Color::Red),
+        ];
+        const KINDS: &[(FileKind, &str, Color)] = &[
+            (FileKind::Directory, "\u{f07b}", Color::LightBlue),
+            (FileKind::File, "\u{f15b}", Color::White),
+            (FileKind::Executable, "\u{f489}", Color::Green),
+            (FileKind::Symlink, "\u{f0c1}", Color::Cyan),
+            (FileKind::BrokenSymlink, "\u{f127}", Color::Red),
+            (FileKind::Socket, "\u{f1e6}", Color::Magenta),
+            (FileKind::Fifo, "\u{f0ec}", Color::Yellow),
+            (FileKind::BlockDevice, "\u{f0a0}", Color::Yellow),
+            (FileKind::CharDevice, "\u{f11c}", Color::Yellow),
+            (FileKind::Other, "\u{f128}", Color::Gray),
+        ];
+
+        let mut icons = Self::new();
+        for &(name, glyph, color) in NAMES {
+            icons = icons.with_name(name, Icon::new(glyph, color));
+        }
+        for &(extension, glyph, color) in EXTENSIONS {
+            icons = icons.with_extension(extension, Icon::new(glyph, color));
+        }
+        for &(kind, glyph, color) in KINDS {
+            icons = icons.with_kind(kind, Icon::new(glyph, color));
+        }
+
+        icons
+    }
+
+    /// Creates a set of ASCII icons for the terminals without a Nerd Font, showing the kind of the files
+    /// like `ls -l` does (eg. `d` for directories and `l` for symbolic links).
+    #[must_use]
+    pub fn ascii() -> Self {
+        const KINDS: &[(FileKind, &str, Color)] = &[
+            (FileKind::Directory, "d", Color::LightBlue),
+            (FileKind::File, "-", Color::Reset),
+            (FileKind::Executable, "x", Color::Green),
+            (FileKind::Symlink, "l", Color::Cyan),
+            (FileKind::BrokenSymlink, "!", Color::Red),
+            (FileKind::Socket, "s", Color::Magenta),
+            (FileKind::Fifo, "p", Color::Yellow),
+            (FileKind::BlockDevice, "b", Color::Yellow),
+            (FileKind::CharDevice, "c", Color::Yellow),
+            (FileKind::Other, "?", Color::Reset),
+        ];
+
+        KINDS
+            .iter()
+            .fold(Self::new(), |icons, &(kind, glyph, color)| {
+                icons.with_kind(kind, Icon::new(glyph, color))
+            })
+    }
+
+    /// Set the icon of the files named `name` (eg. `Makefile`).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_name<S: Into<String>>(mut self, name: S, icon: Icon) -> Self {
+        self.names.insert(name.into(), icon);
+        self
+    }
+
+    /// Set the icon of the regular files with the extension `extension` (without the leading dot, case insensitive).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_extension<S: AsRef<str>>(mut self, extension: S, icon: Icon) -> Self {
+        self.extensions
+            .insert(extension.as_ref().to_lowercase(), icon);
+        self
+    }
+
+    /// Set the icon of the files of the kind `kind`.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_kind(mut self, kind: FileKind, icon: Icon) -> Self {
+        match self.kinds.iter_mut().find(|(other, _)| *other == kind) {
+            Some((_, other)) => *other = icon,
+            None => self.kinds.push((kind, icon)),
+        }
+        self
+    }
+
+    /// Returns the icon of `file`, if any.
+    #[must_use]
+    pub fn icon(&self, file: &File) -> Option<&Icon> {
+        let by_name = || self.names.get(file.file_name.to_str()?);
+        let by_extension = || {
+            if !matches!(file.kind, FileKind::File | FileKind::Executable) {
+                return None;
+            }
+            let extension = file.path.extension()?.to_str()?.to_lowercase();
+            self.extensions.get(&extension)
+        };
+        let by_kind = || {
+            self.kinds
+                .iter()
+                .find(|(kind, _)| *kind == file.kind)
+                .map(|(_, icon)| icon)
+        };
+
+        by_name().or_else(by_extension).or_else(by_kind)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::fs;
+    use tempfile::TempDir;
+
+    use crate::{FileExplorerBuilder, Result};
+
+    #[test]
+    fn test_icons_lookup() -> Result<()> {
+        let root = TempDir::new()?;
+        fs::create_dir(root.path().join(".git"))?;
+        fs::create_dir(root.path().join("src"))?;
+        fs::write(root.path().join("Cargo.toml"), "")?;
+        fs::write(root.path().join("README.MD"), "")?;
+        fs::write(root.path().join("unknown.xyz"), "")?;
+
+        let file_explorer = FileExplorerBuilder::default()
+            .working_dir(root.path())
+            .show_hidden(true)
+            .build()?;
+
+        let icons = Icons::nerd_font().with_extension("XYZ", Icon::new("x", Color::Reset));
+        let glyphs: Vec<_> = file_explorer
+            .files()
+            .iter()
+            .map(|file| icons.icon(file).map(|icon| icon.glyph.as_str()))
+            .collect();
+        assert_eq!(
+            glyphs,
+            [
+                Some("\u{f07b}"), // ../
+                Some("\u{e5fb}"), // .git/
+                Some("\u{f07b}"), // src/
+                Some("\u{e7a8}"), // Cargo.toml
+                Some("\u{e73e}"), // README.MD
+                Some("x"),        // unknown.xyz
+            ]
+        );
+
+        let icons = Icons::ascii();
+        let glyphs: Vec<_> = file_explorer
+            .files()
+            .iter()
+            .map(|file| icons.icon(file).map(|icon| icon.glyph.as_str()))
+            .collect();
+        assert_eq!(
+            glyphs,
+            [
+                Some("d"),
+                Some("d"),
+                Some("d"),
+                Some("-"),
+                Some("-"),
+                Some("-")
+            ]
+        );
+
+        assert_eq!(Icons::new().icon(file_explorer.current()), None);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,277 @@
+use ratatui::style::{Color, Modifier, Style};
+
+use super::Theme;
+use crate::{File, FileKind, Filter};
+
+impl Theme {
+    /// Create the [default](Theme::default) theme, styling the files like `ls` does according to the
+    /// `LS_COLORS` environment variable.
+    ///
+    /// See [`with_ls_colors`](Theme::with_ls_colors) for more information.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ratatui_explorer::Theme;
+    /// let theme = Theme::from_ls_colors();
+    /// ```
+    #[must_use]
+    pub fn from_ls_colors() -> Self {
+        let ls_colors = std::env::var("LS_COLORS").unwrap_or_default();
+        Theme::default().with_ls_colors(&ls_colors)
+    }
+
+    /// Add the [style rules](Theme::with_style_rule) described by `ls_colors`, in the format of the
+    /// `LS_COLORS` environment variable (as generated by `dircolors`).
+    ///
+    /// Like `ls`, the styles of the kinds of files (`di`, `ln`, `or`, `ex`, `so`, `pi`, `bd` and `cd`)
+    /// come first, then the styles of the extensions (`*.ext`), which only apply to the regular files,
+    /// then the style of the regular files (`fi`). The other entries and the malformed ones are ignored.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ratatui_explorer::Theme;
+    /// let theme = Theme::default().with_ls_colors("di=01;34:ln=01;36:ex=01;32:*.tar=01;31");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_ls_colors(mut self, ls_colors: &str) -> Self {
+        let mut kinds = Vec::new();
+        let mut suffixes = Vec::new();
+        let mut regular = None;
+
+        for entry in ls_colors.split(':') {
+            let Some((key, style)) = entry.split_once('=') else {
+                continue;
+            };
+            let Some(style) = parse_sgr(style) else {
+                continue;
+            };
+
+            if let Some(suffix) = key.strip_prefix('*') {
+                suffixes.push((suffix.to_owned(), style));
+                continue;
+            }
+
+            let kind = match key {
+                "di" => FileKind::Directory,
+                "ln" => FileKind::Symlink,
+                "or" => FileKind::BrokenSymlink,
+                "ex" => FileKind::Executable,
+                "so" => FileKind::Socket,
+                "pi" => FileKind::Fifo,
+                "bd" => FileKind::BlockDevice,
+                "cd" => FileKind::CharDevice,
+                "fi" => {
+                    regular = Some(style);
+                    continue;
+                }
+                _ => continue,
+            };
+            kinds.push((kind, style));
+        }
+
+        // Broken symbolic links are styled like the other links if they have no style of their own.
+        if !kinds
+            .iter()
+            .any(|(kind, _)| *kind == FileKind::BrokenSymlink)
+            && let Some(&(_, style)) = kinds.iter().find(|(kind, _)| *kind == FileKind::Symlink)
+        {
+            kinds.push((FileKind::BrokenSymlink, style));
+        }
+
+        for (kind, style) in kinds {
+            self = self.with_style_rule(Filter::file_type(kind), style);
+        }
+        for (suffix, style) in suffixes {
+            let filter =
+                Filter::new(move |file| file.kind == FileKind::File && has_suffix(file, &suffix));
+            self = self.with_style_rule(filter, style);
+        }
+        if let Some(style) = regular {
+            self = self.with_style_rule(Filter::file_type(FileKind::File), style);
+        }
+
+        self
+    }
+}
+
+/// Returns `true` if the file name of `file` ends with `suffix`, ignoring the ASCII case.
+fn has_suffix(file: &File, suffix: &str) -> bool {
+    let name = file.file_name.as_encoded_bytes();
+
+    name.len() >= suffix.len()
+        && name[name.len() - suffix.len()..].eq_ignore_ascii_case(suffix.as_bytes())
+}
+
+/// Parses the SGR parameters of an `LS_COLORS` entry (eg. `01;38;5;208`).
+fn parse_sgr(parameters: &str) -> Option<Style> {
+    const ANSI_COLORS: [Color; 16] = [
+        Color::Black,
+        Color::Red,
+        Color::Green,
+        Color::Yellow,
+        Color::Blue,
+        Color::Magenta,
+        Color::Cyan,
+        Color::Gray,
+        Color::DarkGray,
+        Color::LightRed,
+        Color::LightGreen,
+        Color::LightYellow,
+        Color::LightBlue,
+        Color::LightMagenta,
+        Color::LightCyan,
+        Color::White,
+    ];
+
+    let mut codes = parameters.split(';').map(|code| {
+        if code.is_empty() {
+            Some(0)
+        } else {
+            code.parse::<u8>().ok()
+        }
+    });
+    let mut style = Style::new();
+
+    while let Some(code) = codes.next() {
+        style = match code? {
+            0 => Style::new(),
+            1 => style.add_modifier(Modifier::BOLD),
+            2 => style.add_modifier(Modifier::DIM),
+            3 => style.add_modifier(Modifier::ITALIC),
+            4 => style.add_modifier(Modifier::UNDERLINED),
+            5 => style.add_modifier(Modifier::SLOW_BLINK),
+            6 => style.add_modifier(Modifier::RAPID_BLINK),
+            7 => style.add_modifier(Modifier::REVERSED),
+            8 => style.add_modifier(Modifier::HIDDEN),
+            9 => style.add_modifier(Modifier::CROSSED_OUT),
+            code @ 30..=37 => style.fg(ANSI_COLORS[usize::from(code - 30)]),
+            code @ 40..=47 => style.bg(ANSI_COLORS[usize::from(code - 40)]),
+            code @ 90..=97 => style.fg(ANSI_COLORS[usize::from(code - 90 + 8)]),
+            code @ 100..=107 => style.bg(ANSI_COLORS[usize::from(code - 100 + 8)]),
+            39 => style.fg(Color::Reset),
+            49 => style.bg(Color::Reset),
+            code @ (38 | 48) => {
+                let color = match codes.next()?? {
+                    5 => Color::Indexed(codes.next()??),
+                    2 => Color::Rgb(codes.next()??, codes.next()??, codes.next()??),
+                    _ => return None,
+                };
+
+                if code == 38 {
+                    style.fg(color)
+                } else {
+                    style.bg(color)
+                }
+            }
+            _ => style,
+        };
+    }
+
+    Some(style)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::fs;
+    use tempfile::TempDir;
+
+    use crate::{FileExplorerBuilder, Result};
+
+    /// Build this temporary file system:
+    /// ```plaintext
+    /// <unknow>
+    /// └ root
+    ///   ├── archive.TAR.GZ
+    ///   ├── broken -> missing
+    ///   ├── Documents
+    ///   ├── notes.txt
+    ///   └── run.sh  (executable)
+    /// ```
+    #[cfg(unix)]
+    fn build_tmp_file_system() -> Result<TempDir> {
+        use std::os::unix::fs::PermissionsExt;
+
+        let root = TempDir::new()?;
+
+        fs::create_dir(root.path().join("Documents"))?;
+        fs::write(root.path().join("archive.TAR.GZ"), "")?;
+        fs::write(root.path().join("notes.txt"), "")?;
+        fs::write(root.path().join("run.sh"), "")?;
+        fs::set_permissions(
+            root.path().join("run.sh"),
+            fs::Permissions::from_mode(0o755),
+        )?;
+        std::os::unix::fs::symlink(root.path().join("missing"), root.path().join("broken"))?;
+
+        Ok(root)
+    }
+
+    #[test]
+    fn test_parse_sgr() {
+        assert_eq!(
+            parse_sgr("01;34"),
+            Some(Style::new().add_modifier(Modifier::BOLD).fg(Color::Blue))
+        );
+        assert_eq!(
+            parse_sgr("38;5;208;48;2;1;2;3"),
+            Some(Style::new().fg(Color::Indexed(208)).bg(Color::Rgb(1, 2, 3)))
+        );
+        assert_eq!(
+            parse_sgr("4;93"),
+            Some(
+                Style::new()
+                    .add_modifier(Modifier::UNDERLINED)
+                    .fg(Color::LightYellow)
+            )
+        );
+        assert_eq!(parse_sgr("target"), None);
+        assert_eq!(parse_sgr("38;5"), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_files_are_styled_like_ls() -> Result<()> {
+        let root = build_tmp_file_system()?;
+
+        let theme = Theme::new().with_ls_colors(
+            "rs=0:di=01;34:ln=target:or=40;31:ex=01;32:*.tar.gz=01;31:fi=33:*.sh=35:bogus",
+        );
+        let file_explorer = FileExplorerBuilder::default()
+            .working_dir(root.path())
+            .theme(theme)
+            .build()?;
+
+        let styles: Vec<_> = file_explorer
+            .files()
+            .iter()
+            .map(|file| (file.name.as_str(), file_explorer.theme().file_style(file)))
+            .collect();
+        assert_eq!(
+            styles,
+            [
+                (
+                    "../",
+                    Style::new().add_modifier(Modifier::BOLD).fg(Color::Blue)
+                ),
+                (
+                    "Documents/",
+                    Style::new().add_modifier(Modifier::BOLD).fg(Color::Blue)
+                ),
+                (
+                    "archive.TAR.GZ",
+                    Style::new().add_modifier(Modifier::BOLD).fg(Color::Red)
+                ),
+                ("broken", Style::new().bg(Color::Black).fg(Color::Red)),
+                ("notes.txt", Style::new().fg(Color::Yellow)),
+                (
+                    "run.sh",
+                    Style::new().add_modifier(Modifier::BOLD).fg(Color::Green)
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,40 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::Modifier,
+    text::Line,
+    widgets::{Block, List, ListState, StatefulWidget, WidgetRef},
+};
+
+use crate::{FileExplorer, Places};
+
+pub struct PlacesRenderer<'a>(pub(crate) &'a Places, pub(crate) &'a FileExplorer);
+
+impl WidgetRef for PlacesRenderer<'_> {
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        let theme = self.1.theme();
+
+        let items = self.0.places().iter().map(|place| {
+            let mut style = theme.dir_style;
+            if place.path == *self.1.cwd() {
+                style = style.add_modifier(Modifier::BOLD);
+            }
+            Line::styled(place.name.as_str(), style)
+        });
+
+        // The selection is only shown while the sidebar has the focus.
+        let selected = self.0.is_focused().then_some(self.0.selected_idx());
+        let mut state = ListState::default().with_selected(selected);
+
+        let mut list = List::new(items)
+            .block(Block::bordered().title(" Places "))
+            .style(theme.style)
+            .highlight_spacing(theme.highlight_spacing.clone())
+            .highlight_style(theme.highlight_dir_style);
+        if let Some(symbol) = theme.highlight_symbol.as_deref() {
+            list = list.highlight_symbol(symbol);
+        }
+
+        StatefulWidget::render(&list, area, buf, &mut state);
+    }
+}
@@ -0,0 +1,35 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Layout, Rect},
+    widgets::{Tabs, Widget, WidgetRef},
+};
+
+use crate::ExplorerTabs;
+
+pub struct TabsRenderer<'a>(pub(crate) &'a ExplorerTabs);
+
+impl WidgetRef for TabsRenderer<'_> {
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        let file_explorer = self.0.current();
+        let theme = file_explorer.theme();
+
+        let [bar_area, listing_area] =
+            Layout::vertical([Constraint::Length(1), Constraint::Fill(1)]).areas(area);
+
+        // The root has no file name, so it is shown as a whole.
+        let titles = self.0.tabs().iter().map(|tab| {
+            let cwd = tab.cwd();
+            cwd.file_name().map_or_else(
+                || cwd.display().to_string(),
+                |name| name.to_string_lossy().into_owned(),
+            )
+        });
+        let bar = Tabs::new(titles)
+            .select(self.0.selected_idx())
+            .style(theme.style)
+            .highlight_style(theme.highlight_dir_style);
+        Widget::render(bar, bar_area, buf);
+
+        file_explorer.widget().render_ref(listing_area, buf);
+    }
+}